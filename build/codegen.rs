@@ -7,6 +7,16 @@ use std::{
 
 // crates.io
 use scraper::{ElementRef, Html, Selector};
+// self
+use language::{
+	plural::{PluralRelation, PluralRules, PluralSampleList},
+	tag::LanguageTag,
+};
+
+const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+// CLDR root rules, used by languages without plural data.
+const CARDINAL_ROOT: &str = "@integer 0~15, 100, 1000, 10000, 100000, 1000000, … @decimal 0.0~1.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …";
+const ORDINAL_ROOT: &str = "@integer 0~15, 100, 1000, 10000, 100000, 1000000, …";
 
 #[derive(Debug)]
 pub enum CodegenError {
@@ -23,39 +33,142 @@ impl Display for CodegenError {
 }
 impl StdError for CodegenError {}
 
+pub struct Sources<'a> {
+	pub languages_html: &'a str,
+	pub plurals_xml: &'a str,
+	pub ordinals_xml: &'a str,
+	pub plural_ranges_xml: &'a str,
+	pub likely_subtags_xml: &'a str,
+	pub lcids_txt: &'a str,
+	pub iso639_txt: &'a str,
+}
+
 #[derive(Debug)]
 struct TagSpec {
 	ident: String,
 	tag: String,
 	english: String,
 	autonym: String,
+	plural_categories: Vec<&'static str>,
+	cardinal_rules: usize,
+	ordinal_rules: usize,
+	range_rules: usize,
+	lcid: Option<u32>,
+	iso639: usize,
+}
+
+#[derive(Debug)]
+struct Iso639 {
+	tag: String,
+	// ISO 639-1, 639-2/T, 639-2/B and 639-3; `None` where the standard has no code.
+	codes: [Option<String>; 4],
+}
+
+#[derive(Debug)]
+struct PluralData {
+	cardinals: Vec<PluralRuleSet>,
+	ordinals: Vec<PluralRuleSet>,
+	ranges: Vec<PluralRangeSet>,
+}
+
+#[derive(Debug)]
+struct PluralRuleSet {
+	name: String,
+	locales: Vec<String>,
+	// `other` keeps an empty condition; CLDR defines it as the fallback category.
+	rules: Vec<(&'static str, Vec<Vec<Relation>>)>,
+	// Expanded `@integer` and `@decimal` samples, aligned with `rules`.
+	samples: Vec<(Vec<String>, Vec<String>)>,
 }
 
-pub fn generate(languages_html: &str) -> Result<String, CodegenError> {
-	let specs = load_languages(languages_html)?;
+#[derive(Debug)]
+struct PluralRangeSet {
+	name: String,
+	locales: Vec<String>,
+	// `(start, end, result)`; pairs missing here take the end category.
+	ranges: Vec<(&'static str, &'static str, &'static str)>,
+}
 
-	Ok(render(&specs))
+#[derive(Debug)]
+enum Folded {
+	Always,
+	Never,
+	Test(String),
+}
+
+#[derive(Debug)]
+struct Relation {
+	operand: char,
+	modulus: Option<u64>,
+	negated: bool,
+	ranges: Vec<(u64, u64)>,
+}
+
+pub fn generate(sources: &Sources) -> Result<String, CodegenError> {
+	let mut specs = load_languages(sources.languages_html)?;
+	let plurals = PluralData {
+		cardinals: load_plural_rules(sources.plurals_xml, CARDINAL_ROOT)?,
+		ordinals: load_plural_rules(sources.ordinals_xml, ORDINAL_ROOT)?,
+		ranges: load_plural_ranges(sources.plural_ranges_xml)?,
+	};
+
+	let likely_subtags = load_likely_subtags(sources.likely_subtags_xml)?;
+	let lcids = load_lcids(sources.lcids_txt)?;
+	let iso639 = load_iso639(sources.iso639_txt)?;
+
+	assign_plural_rules(&mut specs, &plurals)?;
+
+	for spec in &mut specs {
+		let base = spec.tag.split('-').next().unwrap_or_default();
+
+		spec.lcid = lcids.iter().find(|(_, tag)| *tag == spec.tag).map(|(lcid, _)| *lcid);
+		spec.iso639 = iso639.iter().position(|row| row.tag == base).ok_or_else(|| {
+			CodegenError::Validation(format!("Missing ISO 639 codes (tag {}).", spec.tag))
+		})?;
+	}
+
+	Ok(render(&specs, &plurals, &likely_subtags, &lcids, &iso639))
 }
 
 fn load_languages(languages_html: &str) -> Result<Vec<TagSpec>, CodegenError> {
 	let document = Html::parse_document(languages_html);
+	let header_selector = Selector::parse("#languages-table thead th")
+		.map_err(|err| CodegenError::Parse(format!("Invalid selector: {err}")))?;
 	let row_selector = Selector::parse("#languages-table tbody tr")
 		.map_err(|err| CodegenError::Parse(format!("Invalid selector: {err}")))?;
 	let cell_selector = Selector::parse("td")
 		.map_err(|err| CodegenError::Parse(format!("Invalid selector: {err}")))?;
-	let mut merged: BTreeMap<String, (String, String)> = BTreeMap::new();
+	// Plural cases are published as one column per CLDR category; locate them by header so column
+	// order changes upstream do not shift categories.
+	let plural_columns = document
+		.select(&header_selector)
+		.map(extract_text)
+		.enumerate()
+		.filter_map(|(i, header)| {
+			PLURAL_CATEGORIES
+				.into_iter()
+				.find(|category| header.eq_ignore_ascii_case(category))
+				.map(|category| (i, category))
+		})
+		.collect::<Vec<_>>();
+
+	if plural_columns.is_empty() {
+		return Err(CodegenError::Parse("No plural category columns found in source file.".into()));
+	}
+
+	let mut merged: BTreeMap<String, (String, String, Vec<&'static str>)> = BTreeMap::new();
 
 	for row in document.select(&row_selector) {
-		let mut cells = row.select(&cell_selector).take(4).map(extract_text).collect::<Vec<_>>();
+		let cells = row.select(&cell_selector).map(extract_text).collect::<Vec<_>>();
 
 		if cells.len() < 4 {
 			continue;
 		}
 
-		let tag = cells.remove(0);
-		let name = cells.remove(0);
-		let region = cells.remove(0);
-		let native = cells.remove(0);
+		let tag = cells[0].clone();
+		let name = cells[1].clone();
+		let region = cells[2].clone();
+		let native = cells[3].clone();
 		// Skip translation.io synthetic/system-specific tags that are not valid BCP47 language
 		// codes.
 		let lower_tag = tag.to_ascii_lowercase();
@@ -70,7 +183,15 @@ fn load_languages(languages_html: &str) -> Result<Vec<TagSpec>, CodegenError> {
 			continue;
 		}
 
-		merged.entry(tag).or_insert((english, native));
+		let mut plural_categories = plural_columns
+			.iter()
+			.filter(|(i, _)| cells.get(*i).is_some_and(|cell| !cell.is_empty()))
+			.map(|(_, category)| *category)
+			.collect::<Vec<_>>();
+
+		plural_categories
+			.sort_by_key(|category| PLURAL_CATEGORIES.iter().position(|known| known == category));
+		merged.entry(tag).or_insert((english, native, plural_categories));
 	}
 
 	if merged.is_empty() {
@@ -79,18 +200,547 @@ fn load_languages(languages_html: &str) -> Result<Vec<TagSpec>, CodegenError> {
 
 	let mut specs = Vec::with_capacity(merged.len());
 
-	for (tag, (english, native)) in merged {
+	for (tag, (english, native, plural_categories)) in merged {
 		validate_tag(&tag).map_err(|msg| CodegenError::Validation(format!("{msg} (tag {tag})")))?;
+		validate_plural_categories(&plural_categories)
+			.map_err(|msg| CodegenError::Validation(format!("{msg} (tag {tag})")))?;
 
 		let ident = tag_to_ident(&tag)
 			.map_err(|msg| CodegenError::Validation(format!("{msg} (tag {tag})")))?;
 
-		specs.push(TagSpec { ident, tag, english, autonym: native });
+		specs.push(TagSpec {
+			ident,
+			tag,
+			english,
+			autonym: native,
+			plural_categories,
+			cardinal_rules: 0,
+			ordinal_rules: 0,
+			range_rules: 0,
+			lcid: None,
+			iso639: 0,
+		});
 	}
 
 	Ok(specs)
 }
 
+fn load_plural_rules(plurals_xml: &str, root: &str) -> Result<Vec<PluralRuleSet>, CodegenError> {
+	// CLDR supplemental data is XML; the HTML parser lowercases element names but keeps the
+	// structure we need.
+	let document = Html::parse_document(plurals_xml);
+	let rules_selector = Selector::parse("pluralrules")
+		.map_err(|err| CodegenError::Parse(format!("Invalid selector: {err}")))?;
+	let rule_selector = Selector::parse("pluralrule")
+		.map_err(|err| CodegenError::Parse(format!("Invalid selector: {err}")))?;
+	let mut sets = Vec::new();
+
+	for element in document.select(&rules_selector) {
+		let locales = element
+			.value()
+			.attr("locales")
+			.unwrap_or_default()
+			.split_whitespace()
+			.map(|locale| locale.replace('_', "-"))
+			.collect::<Vec<_>>();
+		let Some(name) = locales.first().map(|locale| locale.replace('-', "_").to_lowercase())
+		else {
+			return Err(CodegenError::Parse("Plural rules without locales.".into()));
+		};
+		let texts = element
+			.select(&rule_selector)
+			.map(|rule| {
+				(rule.value().attr("count").unwrap_or_default(), rule.text().collect::<String>())
+			})
+			.collect::<Vec<_>>();
+		let parsed = PluralRules::parse(texts.iter().map(|(count, text)| (*count, text.as_str())))
+			.map_err(|err| CodegenError::Validation(format!("{err} (locale {name})")))?;
+
+		sets.push(lower_rule_set(name, locales, &parsed)?);
+	}
+
+	if sets.is_empty() {
+		return Err(CodegenError::Parse("No plural rules found in source file.".into()));
+	}
+
+	// Languages without CLDR data use the root locale, which only has `other`.
+	let parsed = PluralRules::parse([("other", root)])
+		.map_err(|err| CodegenError::Validation(format!("{err} (locale root)")))?;
+
+	sets.push(lower_rule_set("root".into(), Vec::new(), &parsed)?);
+
+	Ok(sets)
+}
+
+fn lower_rule_set(
+	name: String,
+	locales: Vec<String>,
+	parsed: &PluralRules,
+) -> Result<PluralRuleSet, CodegenError> {
+	// Every `@integer`/`@decimal` sample must select its own rule.
+	parsed.validate().map_err(|err| CodegenError::Validation(format!("{err} (locale {name})")))?;
+
+	let rules = parsed
+		.rules()
+		.iter()
+		.map(|(category, rule)| {
+			let condition = rule
+				.condition()
+				.iter()
+				.map(|and_condition| and_condition.iter().map(lower_relation).collect())
+				.collect();
+
+			(category.keyword(), condition)
+		})
+		.collect();
+	let samples = parsed
+		.rules()
+		.iter()
+		.map(|(_, rule)| {
+			(
+				rule.integer_samples().map(PluralSampleList::values).unwrap_or_default(),
+				rule.decimal_samples().map(PluralSampleList::values).unwrap_or_default(),
+			)
+		})
+		.collect::<Vec<_>>();
+
+	if samples.iter().all(|(integers, _)| integers.is_empty()) {
+		return Err(CodegenError::Validation(format!(
+			"Plural rules have no `@integer` samples (locale {name})."
+		)));
+	}
+
+	Ok(PluralRuleSet { name, locales, rules, samples })
+}
+
+fn load_plural_ranges(plural_ranges_xml: &str) -> Result<Vec<PluralRangeSet>, CodegenError> {
+	let document = Html::parse_document(plural_ranges_xml);
+	let ranges_selector = Selector::parse("pluralranges")
+		.map_err(|err| CodegenError::Parse(format!("Invalid selector: {err}")))?;
+	let range_selector = Selector::parse("pluralrange")
+		.map_err(|err| CodegenError::Parse(format!("Invalid selector: {err}")))?;
+	let mut sets = Vec::new();
+
+	for element in document.select(&ranges_selector) {
+		let locales = element
+			.value()
+			.attr("locales")
+			.unwrap_or_default()
+			.split_whitespace()
+			.map(|locale| locale.replace('_', "-"))
+			.collect::<Vec<_>>();
+		let Some(name) = locales.first().map(|locale| locale.replace('-', "_").to_lowercase())
+		else {
+			return Err(CodegenError::Parse("Plural ranges without locales.".into()));
+		};
+		let mut ranges = Vec::new();
+
+		for range in element.select(&range_selector) {
+			let category = |attr| {
+				let keyword = range.value().attr(attr).unwrap_or_default();
+
+				PLURAL_CATEGORIES.into_iter().find(|category| *category == keyword).ok_or_else(
+					|| {
+						CodegenError::Validation(format!(
+							"Unknown plural category `{keyword}` (locale {name})."
+						))
+					},
+				)
+			};
+
+			ranges.push((category("start")?, category("end")?, category("result")?));
+		}
+
+		sets.push(PluralRangeSet { name, locales, ranges });
+	}
+
+	if sets.is_empty() {
+		return Err(CodegenError::Parse("No plural ranges found in source file.".into()));
+	}
+
+	sets.push(PluralRangeSet { name: "root".into(), locales: Vec::new(), ranges: Vec::new() });
+
+	Ok(sets)
+}
+
+fn load_likely_subtags(likely_subtags_xml: &str) -> Result<Vec<(String, String)>, CodegenError> {
+	let document = Html::parse_document(likely_subtags_xml);
+	let selector = Selector::parse("likelysubtag")
+		.map_err(|err| CodegenError::Parse(format!("Invalid selector: {err}")))?;
+	let mut likely_subtags = BTreeMap::new();
+
+	for element in document.select(&selector) {
+		let attr = |name| element.value().attr(name).unwrap_or_default().replace('_', "-");
+		let (from, to) = (attr("from"), attr("to"));
+		let invalid =
+			|msg: &str| CodegenError::Validation(format!("{msg} (likely subtags {from})."));
+		let source =
+			LanguageTag::try_from(from.as_str()).map_err(|_| invalid("Malformed source"))?;
+		let target = LanguageTag::try_from(to.as_str()).map_err(|_| invalid("Malformed target"))?;
+
+		// Sources only carry the fields used by the lookup; targets are full triples.
+		if source.to_string() != from
+			|| !source.variants().is_empty()
+			|| !source.extensions().is_empty()
+			|| source.is_private_use()
+		{
+			return Err(invalid("Source must be `language[-Script][-REGION]`"));
+		}
+		if target.to_string() != to
+			|| target.language() == Some("und")
+			|| target.script().is_none()
+			|| target.region().is_none()
+			|| to.split('-').count() != 3
+		{
+			return Err(invalid("Target must be `language-Script-REGION`"));
+		}
+		if likely_subtags.insert(from.clone(), to).is_some() {
+			return Err(invalid("Duplicate source"));
+		}
+	}
+
+	if likely_subtags.is_empty() {
+		return Err(CodegenError::Parse("No likely subtags found in source file.".into()));
+	}
+
+	Ok(likely_subtags.into_iter().collect())
+}
+
+fn load_lcids(lcids_txt: &str) -> Result<Vec<(u32, String)>, CodegenError> {
+	let mut lcids = BTreeMap::new();
+
+	for line in
+		lcids_txt.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#'))
+	{
+		let invalid = |msg: &str| CodegenError::Validation(format!("{msg} (LCID line `{line}`)."));
+		let Some((lcid, tag)) = line.split_once(' ') else {
+			return Err(invalid("Expected `LCID tag`"));
+		};
+		let lcid = lcid
+			.strip_prefix("0x")
+			.and_then(|hex| u32::from_str_radix(hex, 16).ok())
+			.filter(|lcid| *lcid <= 0xFFFF)
+			.ok_or_else(|| invalid("Malformed LCID"))?;
+
+		if LanguageTag::try_from(tag).map(|parsed| parsed.to_string()).ok().as_deref() != Some(tag)
+		{
+			return Err(invalid("Malformed tag"));
+		}
+		if lcids.values().any(|existing| existing == tag) {
+			return Err(invalid("Duplicate tag"));
+		}
+		if lcids.insert(lcid, tag.to_owned()).is_some() {
+			return Err(invalid("Duplicate LCID"));
+		}
+	}
+
+	if lcids.is_empty() {
+		return Err(CodegenError::Parse("No LCIDs found in source file.".into()));
+	}
+
+	Ok(lcids.into_iter().collect())
+}
+
+fn load_iso639(iso639_txt: &str) -> Result<Vec<Iso639>, CodegenError> {
+	let mut rows = Vec::<Iso639>::new();
+
+	for line in
+		iso639_txt.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#'))
+	{
+		let invalid =
+			|msg: &str| CodegenError::Validation(format!("{msg} (ISO 639 line `{line}`)."));
+		let [tag, iso639_1, iso639_2t, iso639_2b, iso639_3] =
+			line.split_whitespace().collect::<Vec<_>>()[..]
+		else {
+			return Err(invalid("Expected `tag 639-1 639-2/T 639-2/B 639-3`"));
+		};
+		let [iso639_1, iso639_2t, iso639_2b, iso639_3] = [iso639_1, iso639_2t, iso639_2b, iso639_3]
+			.map(|code| (code != "-").then(|| code.to_owned()));
+		let is_code = |code: &Option<String>, len| {
+			code.as_ref().is_none_or(|code| {
+				code.len() == len && code.bytes().all(|b| b.is_ascii_lowercase())
+			})
+		};
+
+		if iso639_1.as_deref() != (tag.len() == 2).then_some(tag) {
+			return Err(invalid("639-1 must match a 2-letter tag"));
+		}
+		if !is_code(&iso639_2t, 3)
+			|| !is_code(&iso639_2b, 3)
+			|| !is_code(&iso639_3, 3)
+			|| iso639_2t.is_some() != iso639_2b.is_some()
+			|| iso639_3.is_none()
+		{
+			return Err(invalid("Malformed codes"));
+		}
+
+		let codes = [iso639_1, iso639_2t, iso639_2b, iso639_3];
+
+		for row in &rows {
+			if row.tag == tag
+				|| row.codes.iter().zip(&codes).any(|(lhs, rhs)| lhs.is_some() && lhs == rhs)
+			{
+				return Err(invalid("Duplicate tag or code"));
+			}
+		}
+
+		rows.push(Iso639 { tag: tag.to_owned(), codes });
+	}
+
+	if rows.is_empty() {
+		return Err(CodegenError::Parse("No ISO 639 codes found in source file.".into()));
+	}
+
+	Ok(rows)
+}
+
+fn assign_plural_rules(specs: &mut [TagSpec], plurals: &PluralData) -> Result<(), CodegenError> {
+	for spec in specs {
+		spec.cardinal_rules = find_rule_set(&spec.tag, &plurals.cardinals, |set| &set.locales);
+		spec.ordinal_rules = find_rule_set(&spec.tag, &plurals.ordinals, |set| &set.locales);
+		spec.range_rules = find_rule_set(&spec.tag, &plurals.ranges, |set| &set.locales);
+
+		let categories = plurals.cardinals[spec.cardinal_rules]
+			.rules
+			.iter()
+			.map(|(category, _)| *category)
+			.collect::<Vec<_>>();
+
+		if categories != spec.plural_categories {
+			return Err(CodegenError::Validation(format!(
+				"Plural categories {:?} disagree with CLDR {:?} (tag {}).",
+				spec.plural_categories, categories, spec.tag
+			)));
+		}
+
+		let ranges = &plurals.ranges[spec.range_rules];
+
+		if let Some(category) = ranges
+			.ranges
+			.iter()
+			.flat_map(|(start, end, result)| [start, end, result])
+			.find(|category| !categories.contains(category))
+		{
+			return Err(CodegenError::Validation(format!(
+				"Plural range category `{category}` is not a cardinal category (tag {}, ranges {}).",
+				spec.tag, ranges.name
+			)));
+		}
+	}
+
+	Ok(())
+}
+
+fn find_rule_set<T, F>(tag: &str, sets: &[T], locales: F) -> usize
+where
+	F: Fn(&T) -> &Vec<String>,
+{
+	let mut subtags = tag.split('-').collect::<Vec<_>>();
+
+	// Walk from the most specific tag (`pt-PT`) to the base language (`pt`).
+	while !subtags.is_empty() {
+		let candidate = subtags.join("-");
+
+		if let Some(i) = sets.iter().position(|set| locales(set).contains(&candidate)) {
+			return i;
+		}
+
+		subtags.pop();
+	}
+
+	sets.len() - 1
+}
+
+fn lower_relation(relation: &PluralRelation) -> Relation {
+	let mut ranges = relation.ranges().to_vec();
+
+	// Membership does not depend on order; merging keeps generated patterns minimal.
+	ranges.sort_unstable();
+	ranges.dedup();
+
+	let ranges = ranges.into_iter().fold(Vec::<(u64, u64)>::new(), |mut merged, (start, end)| {
+		match merged.last_mut() {
+			Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+			_ => merged.push((start, end)),
+		}
+
+		merged
+	});
+
+	Relation {
+		operand: relation.operand().symbol(),
+		modulus: relation.modulus(),
+		negated: relation.negated(),
+		ranges,
+	}
+}
+
+fn compile_condition(condition: &[Vec<Relation>]) -> String {
+	condition
+		.iter()
+		.map(|and_condition| {
+			// A non-negated relation on `n` already requires `n` to be an integer, which lets
+			// negated relations in the same conjunction skip the fraction check.
+			let integral =
+				and_condition.iter().any(|relation| relation.operand == 'n' && !relation.negated);
+			let mut terms = Vec::new();
+
+			if integral {
+				terms.push("o.t == 0".to_string());
+			}
+
+			for relation in and_condition {
+				let test = compile_relation(relation);
+
+				// Ranges on `n` only match integers, so `n != x` holds for every decimal.
+				if relation.operand == 'n' && relation.negated && !integral {
+					terms.push(format!("(o.t != 0 || {test})"));
+				} else {
+					terms.push(test);
+				}
+			}
+
+			terms.join(" && ")
+		})
+		.collect::<Vec<_>>()
+		.join(" || ")
+}
+
+fn compile_relation(relation: &Relation) -> String {
+	// `n` compares through its integer digits once the caller has checked `t == 0`; `e` is the
+	// deprecated spelling of `c`.
+	let operand = match relation.operand {
+		'n' => "o.i".to_string(),
+		'e' => "o.c".to_string(),
+		operand => format!("o.{operand}"),
+	};
+	let lhs = match relation.modulus {
+		Some(modulus) => format!("{operand} % {}", literal(modulus)),
+		None => operand.clone(),
+	};
+	let negation = if relation.negated { "!" } else { "" };
+
+	match (relation.modulus, relation.ranges.as_slice()) {
+		(Some(modulus), [(0, 0)]) =>
+			format!("{negation}{operand}.is_multiple_of({})", literal(modulus)),
+		(_, [(start, end)]) if start == end => {
+			let operator = if relation.negated { "!=" } else { "==" };
+
+			format!("{lhs} {operator} {}", literal(*start))
+		},
+		(_, ranges) => {
+			let patterns = ranges
+				.iter()
+				.map(|(start, end)| {
+					if start == end {
+						literal(*start)
+					} else {
+						format!("{}..={}", literal(*start), literal(*end))
+					}
+				})
+				.collect::<Vec<_>>()
+				.join(" | ");
+
+			format!("{negation}matches!({lhs}, {patterns})")
+		},
+	}
+}
+
+fn compile_gettext(set: &PluralRuleSet) -> String {
+	// gettext only sees integers, so only categories with `@integer` samples get a form; CLDR
+	// leaves them out for categories like Russian `other` that only decimals reach.
+	let forms = set
+		.rules
+		.iter()
+		.zip(&set.samples)
+		.filter(|(_, (integers, _))| !integers.is_empty())
+		.map(|((_, condition), _)| condition)
+		.collect::<Vec<_>>();
+	let mut branches = Vec::new();
+	// The last form takes every integer the others leave.
+	let mut fallback = forms.len() - 1;
+
+	for (index, condition) in forms.iter().enumerate().take(fallback) {
+		match fold_gettext_condition(condition) {
+			Folded::Always => {
+				fallback = index;
+
+				break;
+			},
+			Folded::Never => (),
+			Folded::Test(test) => branches.push(format!("{test} ? {index}")),
+		}
+	}
+
+	let plural = if branches.is_empty() {
+		fallback.to_string()
+	} else {
+		format!("({} : {fallback})", branches.join(" : "))
+	};
+
+	format!("nplurals={}; plural={plural};", forms.len())
+}
+
+fn fold_gettext_condition(condition: &[Vec<Relation>]) -> Folded {
+	let mut tests = Vec::new();
+
+	for and_condition in condition {
+		let mut terms = Vec::new();
+		let mut never = false;
+
+		for relation in and_condition {
+			match fold_gettext_relation(relation) {
+				Folded::Always => (),
+				Folded::Never => never = true,
+				Folded::Test(test) => terms.push(test),
+			}
+		}
+
+		if never {
+			continue;
+		}
+		if terms.is_empty() {
+			return Folded::Always;
+		}
+
+		tests.push(terms.join(" && "));
+	}
+
+	if tests.is_empty() { Folded::Never } else { Folded::Test(tests.join(" || ")) }
+}
+
+fn fold_gettext_relation(relation: &Relation) -> Folded {
+	// For integers `i` equals `n` and every fraction or exponent operand is zero.
+	if !matches!(relation.operand, 'n' | 'i') {
+		let contains = relation.ranges.iter().any(|(start, _)| *start == 0);
+
+		return if contains != relation.negated { Folded::Always } else { Folded::Never };
+	}
+
+	let lhs = match relation.modulus {
+		Some(modulus) => format!("n%{modulus}"),
+		None => "n".into(),
+	};
+	let parts = relation
+		.ranges
+		.iter()
+		.map(|(start, end)| match (relation.negated, start == end, *start == 0) {
+			(false, true, _) => format!("{lhs}=={start}"),
+			(false, false, true) => format!("{lhs}<={end}"),
+			(false, false, false) => format!("{lhs}>={start} && {lhs}<={end}"),
+			(true, true, _) => format!("{lhs}!={start}"),
+			(true, false, true) => format!("{lhs}>{end}"),
+			(true, false, false) => format!("({lhs}<{start} || {lhs}>{end})"),
+		})
+		.collect::<Vec<_>>();
+
+	if relation.negated || parts.len() == 1 {
+		Folded::Test(parts.join(" && "))
+	} else {
+		Folded::Test(format!("({})", parts.join(" || ")))
+	}
+}
+
 fn extract_text(cell: ElementRef<'_>) -> String {
 	let joined = cell.text().collect::<String>();
 	normalize_whitespace(&joined)
@@ -143,6 +793,15 @@ fn validate_tag(tag: &str) -> Result<(), String> {
 	Ok(())
 }
 
+fn validate_plural_categories(categories: &[&str]) -> Result<(), String> {
+	// CLDR requires every rule set to end with `other`; a missing column means the row is broken.
+	if !categories.contains(&"other") {
+		return Err("Plural categories must include `other`.".into());
+	}
+
+	Ok(())
+}
+
 fn normalize_whitespace(input: &str) -> String {
 	let mut out = String::new();
 	let mut last_space = false;
@@ -190,11 +849,163 @@ fn tag_to_ident(tag: &str) -> Result<String, String> {
 	Ok(ident)
 }
 
+fn category_ident(category: &str) -> String {
+	let mut chars = category.chars();
+
+	chars.next().map(|first| first.to_ascii_uppercase()).into_iter().chain(chars).collect()
+}
+
+fn literal(value: u64) -> String {
+	let digits = value.to_string();
+	let mut out = String::new();
+
+	for (i, digit) in digits.chars().enumerate() {
+		if i > 0 && (digits.len() - i).is_multiple_of(3) {
+			out.push('_');
+		}
+
+		out.push(digit);
+	}
+
+	out
+}
+
 fn escape(s: &str) -> String {
 	s.replace('\\', "\\\\").replace('"', "\\\"")
 }
 
-fn render(specs: &[TagSpec]) -> String {
+fn render_rules(out: &mut String, prefix: &str, set: &PluralRuleSet) {
+	let branches = set
+		.rules
+		.iter()
+		.filter(|(category, _)| *category != "other")
+		.map(|(category, condition)| (compile_condition(condition), *category))
+		.collect::<Vec<_>>();
+
+	out.push_str("#[rustfmt::skip]\n");
+
+	if branches.is_empty() {
+		out.push_str(&format!(
+			"fn {prefix}_{}(_: &PluralOperands) -> PluralCategory {{
+	Other
+}}
+",
+			set.name
+		));
+
+		return;
+	}
+
+	out.push_str(&format!(
+		"fn {prefix}_{}(o: &PluralOperands) -> PluralCategory {{
+	",
+		set.name
+	));
+
+	for (expr, category) in branches {
+		out.push_str(&format!(
+			"if {expr} {{
+		{}
+	}} else ",
+			category_ident(category)
+		));
+	}
+
+	out.push_str(
+		"{
+		Other
+	}
+}
+",
+	);
+}
+
+fn render_samples(out: &mut String, set: &PluralRuleSet) {
+	let list = |samples: &[String]| {
+		samples.iter().map(|sample| format!("\"{sample}\"")).collect::<Vec<_>>().join(", ")
+	};
+
+	out.push_str(&format!(
+		"#[rustfmt::skip]
+fn samples_{}(category: PluralCategory) -> Option<PluralSamples> {{
+	let samples = match category {{
+",
+		set.name
+	));
+
+	for ((category, _), (integers, decimals)) in set.rules.iter().zip(&set.samples) {
+		out.push_str(&format!(
+			"		{} => PluralSamples::new(&[{}], &[{}]),
+",
+			category_ident(category),
+			list(integers),
+			list(decimals)
+		));
+	}
+
+	if set.rules.len() < PLURAL_CATEGORIES.len() {
+		out.push_str("		_ => return None,\n");
+	}
+
+	out.push_str(
+		"	};
+
+	Some(samples)
+}
+",
+	);
+}
+
+fn render_ranges(out: &mut String, set: &PluralRangeSet) {
+	let exceptions = set.ranges.iter().filter(|(_, end, result)| end != result).collect::<Vec<_>>();
+
+	out.push_str("#[rustfmt::skip]\n");
+
+	if exceptions.is_empty() {
+		out.push_str(&format!(
+			"fn range_{}(_: PluralCategory, end: PluralCategory) -> PluralCategory {{
+	end
+}}
+",
+			set.name
+		));
+
+		return;
+	}
+
+	out.push_str(&format!(
+		"fn range_{}(start: PluralCategory, end: PluralCategory) -> PluralCategory {{
+	match (start, end) {{
+",
+		set.name
+	));
+
+	for (start, end, result) in exceptions {
+		out.push_str(&format!(
+			"		({}, {}) => {},
+",
+			category_ident(start),
+			category_ident(end),
+			category_ident(result)
+		));
+	}
+
+	out.push_str(
+		"		(_, end) => end,
+	}
+}
+",
+	);
+}
+
+fn render(
+	specs: &[TagSpec],
+	plurals: &PluralData,
+	likely_subtags: &[(String, String)],
+	lcids: &[(u32, String)],
+	iso639: &[Iso639],
+) -> String {
+	let (cardinals, ordinals, ranges) = (&plurals.cardinals, &plurals.ordinals, &plurals.ranges);
 	let mut out = String::new();
 
 	out.push_str(
@@ -204,6 +1015,46 @@ use std::convert::TryFrom;
 // self
 use crate::prelude::*;
 use Language::*;
+use PluralCategory::*;
+
+// CLDR likely subtags, sorted by source for binary search.
+#[rustfmt::skip]
+pub(crate) const LIKELY_SUBTAGS: [(&str, &str); ",
+	);
+	out.push_str(&likely_subtags.len().to_string());
+	out.push_str(
+		"] = [
+",
+	);
+
+	for (from, to) in likely_subtags {
+		out.push_str(&format!(
+			"	(\"{from}\", \"{to}\"),
+",
+		));
+	}
+
+	out.push_str(
+		"];
+// Windows LCIDs, sorted by LCID for binary search.
+#[rustfmt::skip]
+pub(crate) const LCIDS: [(u32, &str); ",
+	);
+	out.push_str(&lcids.len().to_string());
+	out.push_str(
+		"] = [
+",
+	);
+
+	for (lcid, tag) in lcids {
+		out.push_str(&format!(
+			"	(0x{lcid:04X}, \"{tag}\"),
+",
+		));
+	}
+
+	out.push_str(
+		"];
 
 /// Generated from the translation.io languages-with-plural-cases page.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -301,7 +1152,234 @@ impl Language {
 	out.push_str(
 		"		}
 	}
-}
+
+	/// Get the CLDR plural categories used by cardinal numbers in this language.
+	pub fn plural_categories(&self) -> &'static [PluralCategory] {
+		match self {
+",
+	);
+
+	for spec in specs {
+		let categories =
+			spec.plural_categories.iter().map(|c| category_ident(c)).collect::<Vec<_>>().join(", ");
+
+		out.push_str(&format!(
+			"			{} => &[{}],
+",
+			spec.ident, categories
+		));
+	}
+
+	out.push_str(
+		"		}
+	}
+
+	/// Select the CLDR cardinal plural category for an integer.
+	pub fn plural_category(&self, n: u64) -> PluralCategory {
+		self.plural_category_for(&PluralOperands::from(n))
+	}
+
+	/// Select the CLDR cardinal plural category for a formatted number.
+	pub fn plural_category_for(&self, operands: &PluralOperands) -> PluralCategory {
+		match self {
+",
+	);
+
+	for spec in specs {
+		out.push_str(&format!(
+			"			{} => cardinal_{}(operands),
+",
+			spec.ident, cardinals[spec.cardinal_rules].name
+		));
+	}
+
+	out.push_str(
+		"		}
+	}
+
+	/// Get the CLDR sample numbers of a cardinal category, or `None` when the language does not
+	/// use it.
+	pub fn plural_samples(&self, category: PluralCategory) -> Option<PluralSamples> {
+		match self {
+",
+	);
+
+	for spec in specs {
+		out.push_str(&format!(
+			"			{} => samples_{}(category),
+",
+			spec.ident, cardinals[spec.cardinal_rules].name
+		));
+	}
+
+	out.push_str(
+		"		}
+	}
+
+	/// Get the canonical gettext `Plural-Forms` header value derived from the CLDR cardinal rules.
+	///
+	/// Plural form indices follow the categories of [`Self::plural_categories`] that have integer
+	/// samples, in CLDR order, so `ru` has `nplurals=3` for its 4 categories.
+	pub fn gettext_plural_forms(&self) -> &'static str {
+		match self {
+",
+	);
+
+	for spec in specs {
+		out.push_str(&format!(
+			"			{} => gettext_{}(),
+",
+			spec.ident, cardinals[spec.cardinal_rules].name
+		));
+	}
+
+	out.push_str(
+		"		}
+	}
+
+	/// Get the CLDR plural categories used by ordinal numbers in this language.
+	pub fn ordinal_categories(&self) -> &'static [PluralCategory] {
+		match self {
+",
+	);
+
+	for spec in specs {
+		let categories = ordinals[spec.ordinal_rules]
+			.rules
+			.iter()
+			.map(|(category, _)| category_ident(category))
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		out.push_str(&format!(
+			"			{} => &[{}],
+",
+			spec.ident, categories
+		));
+	}
+
+	out.push_str(
+		"		}
+	}
+
+	/// Select the CLDR ordinal plural category for a rank, such as `2` in \"2nd\".
+	pub fn ordinal_category(&self, n: u64) -> PluralCategory {
+		let operands = PluralOperands::from(n);
+
+		match self {
+",
+	);
+
+	for spec in specs {
+		out.push_str(&format!(
+			"			{} => ordinal_{}(&operands),
+",
+			spec.ident, ordinals[spec.ordinal_rules].name
+		));
+	}
+
+	out.push_str(
+		"		}
+	}
+
+	/// Select the CLDR plural category of a range such as \"1–3 days\" from the categories of its
+	/// start and end values.
+	///
+	/// Pairs without CLDR data take the end category.
+	pub fn plural_range_category(
+		&self,
+		start: PluralCategory,
+		end: PluralCategory,
+	) -> PluralCategory {
+		match self {
+",
+	);
+
+	for spec in specs {
+		out.push_str(&format!(
+			"			{} => range_{}(start, end),
+",
+			spec.ident, ranges[spec.range_rules].name
+		));
+	}
+
+	out.push_str(
+		"		}
+	}
+
+	/// Get the Windows locale identifier (LCID).
+	///
+	/// Base languages map to their neutral LCID, such as `0x0009` for `en`. `None` for languages
+	/// Windows only knows as `LOCALE_CUSTOM_UNSPECIFIED`.
+	pub fn lcid(&self) -> Option<u32> {
+		let lcid = match self {
+",
+	);
+
+	for spec in specs {
+		if let Some(lcid) = spec.lcid {
+			out.push_str(&format!(
+				"			{} => 0x{lcid:04X},
+",
+				spec.ident
+			));
+		}
+	}
+
+	out.push_str(
+		"			_ => return None,
+		};
+
+		Some(lcid)
+	}
+",
+	);
+
+	for (i, (name, doc)) in [
+		("iso639_1", "ISO 639-1 code of the base language, such as `de`"),
+		("iso639_2t", "ISO 639-2/T (terminology) code of the base language, such as `deu`"),
+		("iso639_2b", "ISO 639-2/B (bibliographic) code of the base language, such as `ger`"),
+		("iso639_3", "ISO 639-3 code of the base language, such as `deu`"),
+	]
+	.into_iter()
+	.enumerate()
+	{
+		// Every row has a 639-3 code, so only the other accessors are fallible.
+		let optional = i < 3;
+
+		out.push_str(&format!(
+			"
+	/// Get the {doc}.
+	pub fn {name}(&self) -> {} {{
+		match self {{
+",
+			if optional { "Option<&'static str>" } else { "&'static str" }
+		));
+
+		for spec in specs {
+			let code = iso639[spec.iso639].codes[i].as_deref();
+
+			out.push_str(&format!(
+				"			{} => {},
+",
+				spec.ident,
+				match code {
+					Some(code) if optional => format!("Some(\"{code}\")"),
+					Some(code) => format!("\"{code}\""),
+					None => "None".into(),
+				}
+			));
+		}
+
+		out.push_str(
+			"		}
+	}
+",
+		);
+	}
+
+	out.push_str(
+		"}
 impl TryFrom<&str> for Language {
 	type Error = Error;
 
@@ -372,5 +1450,44 @@ impl utoipa::ToSchema for Language {}
 ",
 	);
 
+	for (i, set) in cardinals.iter().enumerate() {
+		if specs.iter().any(|spec| spec.cardinal_rules == i) {
+			out.push('\n');
+			render_rules(&mut out, "cardinal", set);
+		}
+	}
+	for (i, set) in cardinals.iter().enumerate() {
+		if specs.iter().any(|spec| spec.cardinal_rules == i) {
+			out.push_str(&format!(
+				"
+#[rustfmt::skip]
+fn gettext_{}() -> &'static str {{
+	\"{}\"
+}}
+",
+				set.name,
+				compile_gettext(set)
+			));
+		}
+	}
+	for (i, set) in cardinals.iter().enumerate() {
+		if specs.iter().any(|spec| spec.cardinal_rules == i) {
+			out.push('\n');
+			render_samples(&mut out, set);
+		}
+	}
+	for (i, set) in ordinals.iter().enumerate() {
+		if specs.iter().any(|spec| spec.ordinal_rules == i) {
+			out.push('\n');
+			render_rules(&mut out, "ordinal", set);
+		}
+	}
+	for (i, set) in ranges.iter().enumerate() {
+		if specs.iter().any(|spec| spec.range_rules == i) {
+			out.push('\n');
+			render_ranges(&mut out, set);
+		}
+	}
+
 	out
 }
@@ -0,0 +1,262 @@
+//! HTTP `Accept-Language` headers (RFC 9110 section 12.5.4) and content negotiation.
+
+// self
+use crate::{matching, parse, prelude::*};
+
+/// Why [`negotiate`] chose a language.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NegotiationReason {
+	/// The range named a supported language, possibly through a deprecated alias.
+	Exact,
+	/// A supported language has the same likely subtags as the range, such as `zh-TW` ↔ `zh-Hant`.
+	ScriptEquivalent,
+	/// A supported language shares the range's language and script, such as `fr-CH` → `fr`.
+	Fallback,
+	/// The `*` range accepted the first supported language not excluded with `q=0`.
+	Wildcard,
+	/// No range matched, so the caller's default was used.
+	Default,
+}
+
+/// Language chosen by [`negotiate`], with the range and reason for logging.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Negotiation {
+	language: Language,
+	range: Option<LanguageRange>,
+	reason: NegotiationReason,
+}
+impl Negotiation {
+	/// Chosen language.
+	pub fn language(&self) -> Language {
+		self.language
+	}
+
+	/// Range that chose the language; `None` for [`NegotiationReason::Default`].
+	pub fn range(&self) -> Option<&LanguageRange> {
+		self.range.as_ref()
+	}
+
+	/// Why the language was chosen.
+	pub fn reason(&self) -> NegotiationReason {
+		self.reason
+	}
+}
+
+/// Language range of an `Accept-Language` header with its quality value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LanguageRange {
+	range: String,
+	quality: u16,
+	language: Option<Language>,
+}
+impl LanguageRange {
+	/// Range as sent, such as `fr-CH` or `*`.
+	pub fn range(&self) -> &str {
+		&self.range
+	}
+
+	/// Quality value in thousandths, from `0` (not acceptable) to `1_000` (the default).
+	pub fn quality(&self) -> u16 {
+		self.quality
+	}
+
+	/// Supported language with this tag, resolved with [`Language::parse_lenient`]; `None` for `*`
+	/// and unsupported ranges.
+	pub fn language(&self) -> Option<Language> {
+		self.language
+	}
+
+	/// Whether the range is `*`.
+	pub fn is_wildcard(&self) -> bool {
+		self.range == "*"
+	}
+
+	// Basic filtering on the range as sent or on its resolved language, so `iw` matches `he`.
+	fn matches(&self, language: Language) -> bool {
+		matching::basic_matches(&self.range, language.tag())
+			|| self
+				.language
+				.is_some_and(|resolved| matching::basic_matches(resolved.tag(), language.tag()))
+	}
+}
+
+/// Parsed `Accept-Language` header.
+///
+/// Parsing never fails: whitespace and empty list elements are ignored, and so are elements whose
+/// range is not a language range or whose weight is not a valid `q` value (such as `q=2` or
+/// `q=high`), as RFC 9110 lets recipients do. Ranges are kept in decreasing quality, in header
+/// order on ties, including `q=0` ranges that mark languages as not acceptable.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AcceptLanguage {
+	ranges: Vec<LanguageRange>,
+}
+impl AcceptLanguage {
+	/// Ranges in decreasing quality.
+	pub fn ranges(&self) -> &[LanguageRange] {
+		&self.ranges
+	}
+
+	/// Whether the header has no valid range, which accepts any language.
+	pub fn is_empty(&self) -> bool {
+		self.ranges.is_empty()
+	}
+
+	/// Choose the supported language for this header; see [`negotiate`].
+	pub fn negotiate(&self, supported: &[Language], default: Language) -> Negotiation {
+		// Each language takes the quality of the most specific range matching it, `*` being the
+		// least specific, so `ja, *;q=0` keeps `ja` and `en-US, en;q=0` keeps `en-US`.
+		let acceptable = supported
+			.iter()
+			.copied()
+			.filter(|language| {
+				self.ranges
+					.iter()
+					.filter(|range| range.matches(*language))
+					// `max_by_key` keeps the last maximum; reverse to prefer the higher quality.
+					.rev()
+					.max_by_key(|range| {
+						if range.is_wildcard() { 0 } else { range.range.split('-').count() }
+					})
+					.is_none_or(|range| range.quality > 0)
+			})
+			.collect::<Vec<_>>();
+
+		for range in self.ranges.iter().filter(|range| range.quality > 0) {
+			let negotiated = if range.is_wildcard() {
+				acceptable.first().map(|language| (*language, NegotiationReason::Wildcard))
+			} else {
+				negotiate_range(range, &acceptable)
+			};
+
+			if let Some((language, reason)) = negotiated {
+				return Negotiation { language, range: Some(range.clone()), reason };
+			}
+		}
+
+		Negotiation { language: default, range: None, reason: NegotiationReason::Default }
+	}
+}
+impl From<&str> for AcceptLanguage {
+	fn from(value: &str) -> Self {
+		let header = value.trim();
+		let header = match header.split_once(':') {
+			Some((name, value)) if name.trim().eq_ignore_ascii_case("Accept-Language") => value,
+			_ => header,
+		};
+		let mut ranges = header
+			.split(',')
+			.filter_map(|element| {
+				let mut parameters = element.split(';').map(str::trim);
+				let range = parameters.next().filter(|range| is_language_range(range))?;
+				let mut quality = 1_000;
+
+				for parameter in parameters {
+					if let Some((name, value)) = parameter.split_once('=')
+						&& name.trim().eq_ignore_ascii_case("q")
+					{
+						quality = parse_quality(value.trim())?;
+					}
+				}
+
+				Some(LanguageRange {
+					range: range.into(),
+					quality,
+					language: (range != "*").then(|| Language::parse_lenient(range).ok()).flatten(),
+				})
+			})
+			.collect::<Vec<_>>();
+
+		// Stable, so ties keep the header order.
+		ranges.sort_by_key(|range| std::cmp::Reverse(range.quality));
+
+		Self { ranges }
+	}
+}
+
+/// Choose the best supported language for an `Accept-Language` header.
+///
+/// Ranges are tried in decreasing quality. Each picks, in order: the supported language it names,
+/// a supported language with the same likely subtags (`zh-TW` ↔ `zh-Hant`), then one sharing its
+/// likely language and script, preferring no region and then the likely region (`fr-CH` → `fr` →
+/// `fr-FR`), so a fallback for a range wins over lower-quality ranges. `*` picks the first
+/// supported language. Each supported language takes the quality of the most specific range
+/// matching it as sent or through its resolved language (so `iw;q=0` excludes `he`), `*` being
+/// the least specific, and is never picked when that quality is `0`.
+/// Without a match, or for an empty header, `default` is returned.
+pub fn negotiate(header: &str, supported: &[Language], default: Language) -> Negotiation {
+	AcceptLanguage::from(header).negotiate(supported, default)
+}
+
+fn negotiate_range(
+	range: &LanguageRange,
+	acceptable: &[Language],
+) -> Option<(Language, NegotiationReason)> {
+	if let Some(language) = range.language
+		&& acceptable.contains(&language)
+	{
+		return Some((language, NegotiationReason::Exact));
+	}
+
+	let max = parse::normalize_case(&range.range)
+		.and_then(|tag| LanguageTag::try_from(tag.as_str()).ok())?
+		.maximize();
+	let likely = |tag: &LanguageTag| (tag.language.clone(), tag.script.clone(), tag.region.clone());
+
+	if let Some(language) =
+		acceptable.iter().find(|language| likely(&language.maximize()) == likely(&max))
+	{
+		return Some((*language, NegotiationReason::ScriptEquivalent));
+	}
+
+	let base = LanguageTag {
+		language: max.language.clone(),
+		script: max.script.clone(),
+		..Default::default()
+	}
+	.maximize();
+
+	acceptable
+		.iter()
+		.filter(|language| {
+			let candidate = language.maximize();
+
+			candidate.language == max.language && candidate.script == max.script
+		})
+		// `max_by_key` keeps the last maximum; reverse to prefer the supported order on ties.
+		.rev()
+		.max_by_key(|language| {
+			(
+				LanguageTag::from(**language).region.is_none(),
+				likely(&language.maximize()) == likely(&base),
+			)
+		})
+		.map(|language| (*language, NegotiationReason::Fallback))
+}
+
+// RFC 4647 basic language range: `1*8ALPHA *("-" 1*8alphanum)` or `*`.
+fn is_language_range(range: &str) -> bool {
+	range == "*"
+		|| range.split('-').enumerate().all(|(i, subtag)| {
+			(1..=8).contains(&subtag.len())
+				&& subtag.bytes().all(|b| {
+					if i == 0 { b.is_ascii_alphabetic() } else { b.is_ascii_alphanumeric() }
+				})
+		})
+}
+
+// RFC 9110 `qvalue`: `0[.ddd]` or `1[.000]`, in thousandths.
+fn parse_quality(value: &str) -> Option<u16> {
+	let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+
+	if fraction.len() > 3 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+
+	let thousandths = format!("{fraction:0<3}").parse::<u16>().ok()?;
+
+	match integer {
+		"0" => Some(thousandths),
+		"1" if thousandths == 0 => Some(1_000),
+		_ => None,
+	}
+}
@@ -0,0 +1,151 @@
+//! Deprecated language and region subtags and their preferred replacements.
+
+// self
+use crate::{parse, prelude::*};
+
+// Deprecated language subtags from the IANA registry (`Preferred-Value`) and CLDR language aliases.
+// A replacement may carry a script, which is only added when the tag has none.
+const LANGUAGE_ALIASES: [(&str, &str); 8] = [
+	("in", "id"),
+	("iw", "he"),
+	("ji", "yi"),
+	("jw", "jv"),
+	("mo", "ro"),
+	("no", "nb"),
+	("sh", "sr-Latn"),
+	("tl", "fil"),
+];
+// Retired region codes from the IANA registry and CLDR territory aliases. `SP` is not an ISO 3166
+// code but the dataset uses it for Serbia.
+const REGION_ALIASES: [(&str, &str); 9] = [
+	("BU", "MM"),
+	("CS", "RS"),
+	("DD", "DE"),
+	("FX", "FR"),
+	("SP", "RS"),
+	("TP", "TL"),
+	("UK", "GB"),
+	("YU", "RS"),
+	("ZR", "CD"),
+];
+
+/// Deprecated subtag replaced with its preferred value while parsing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TagAlias {
+	deprecated: &'static str,
+	preferred: &'static str,
+}
+impl TagAlias {
+	/// Deprecated subtag found in the input, such as `iw` or `YU`.
+	pub fn deprecated(&self) -> &'static str {
+		self.deprecated
+	}
+
+	/// Preferred replacement, such as `he`, `RS` or `sr-Latn`.
+	pub fn preferred(&self) -> &'static str {
+		self.preferred
+	}
+}
+
+impl Language {
+	/// Parse a tag like [`Language::parse_lenient`] and report the aliases applied.
+	///
+	/// The tag is first matched as is. Otherwise deprecated language and region subtags are
+	/// replaced (`iw` → `he`, `mo` → `ro`, `YU` → `RS`) and the result is matched against the
+	/// dataset tags after applying the same replacements to them, so `sr-Cyrl-RS` resolves to
+	/// [`Language::SrCyrlSp`] with the `SP` → `RS` alias reported. When an alias adding a script
+	/// leaves a tag less specific than the dataset, the variant with the same likely subtags is
+	/// used, so `sh` (`sr-Latn`) gives [`Language::SrLatnSp`]. Tags that exist in the dataset,
+	/// like `no` and `tl`, are never replaced.
+	pub fn parse_with_aliases(tag: &str) -> Result<(Self, Vec<TagAlias>)> {
+		let unsupported = || Error::UnsupportedLanguageTag(tag.into());
+		let normalized = parse::normalize_case(tag).ok_or_else(unsupported)?;
+
+		if let Ok(language) = Self::try_from(normalized.as_str()) {
+			return Ok((language, Vec::new()));
+		}
+
+		let mut aliases = Vec::new();
+		let canonical = canonicalize(&normalized, &mut aliases);
+		let language = Self::try_from(canonical.as_str())
+			.ok()
+			.or_else(|| {
+				// The dataset may keep a retired subtag, as `sr-Cyrl-SP` for `sr-Cyrl-RS`; report
+				// the aliases applied to its tag too.
+				Self::all().into_iter().find(|language| {
+					let mut dataset_aliases = Vec::new();
+
+					(canonicalize(language.tag(), &mut dataset_aliases) == canonical)
+						.then(|| aliases.extend(dataset_aliases))
+						.is_some()
+				})
+			})
+			.or_else(|| {
+				// A replacement adding a script can be less specific than the dataset, as `sh` →
+				// `sr-Latn` where only `sr-Latn-SP` exists; take the variant with the same likely
+				// subtags.
+				let max = LanguageTag::try_from(canonical.as_str()).ok()?.maximize();
+
+				aliases
+					.iter()
+					.any(|alias| alias.preferred.contains('-'))
+					.then(|| Self::all().into_iter().find(|language| language.maximize() == max))?
+			})
+			.ok_or_else(unsupported)?;
+
+		Ok((language, aliases))
+	}
+}
+
+/// Deserialize a [`Language`] leniently, accepting deprecated aliases.
+///
+/// Use with `#[serde(deserialize_with = "language::alias::deserialize")]`; the derived
+/// `Deserialize` implementation keeps requiring canonical tags.
+#[cfg(feature = "serde")]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Language, D::Error>
+where
+	D: serde::de::Deserializer<'de>,
+{
+	let tag = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+	Language::parse_with_aliases(&tag)
+		.map(|(language, _)| language)
+		.map_err(|_| serde::de::Error::unknown_variant(&tag, &[]))
+}
+
+pub(crate) fn preferred_region(region: &str) -> Option<&'static str> {
+	REGION_ALIASES.into_iter().find(|(deprecated, _)| *deprecated == region).map(|(_, p)| p)
+}
+
+// Replace deprecated subtags of a case-normalized tag, recording each replacement.
+pub(crate) fn canonicalize(tag: &str, aliases: &mut Vec<TagAlias>) -> String {
+	let mut subtags = tag.split('-').map(String::from).collect::<Vec<_>>();
+
+	if let Some((deprecated, preferred)) =
+		LANGUAGE_ALIASES.into_iter().find(|(deprecated, _)| *deprecated == subtags[0])
+	{
+		let (language, script) = preferred.split_once('-').unwrap_or((preferred, ""));
+
+		subtags[0] = language.into();
+
+		if !script.is_empty() && subtags.get(1).is_none_or(|subtag| subtag.len() != 4) {
+			subtags.insert(1, script.into());
+		}
+
+		aliases.push(TagAlias { deprecated, preferred });
+	}
+
+	// The region is the first 2-letter or 3-digit subtag before any extension singleton.
+	if let Some(region) =
+		subtags.iter_mut().skip(1).take_while(|subtag| subtag.len() > 1).find(|subtag| {
+			subtag.len() == 2 || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+		}) && let Some((deprecated, preferred)) =
+		REGION_ALIASES.into_iter().find(|(deprecated, _)| deprecated == region)
+	{
+		*region = preferred.into();
+
+		aliases.push(TagAlias { deprecated, preferred });
+	}
+
+	subtags.join("-")
+}
@@ -0,0 +1,83 @@
+// self
+use crate::{alias, prelude::*};
+
+// Android resource types, which may prefix a qualifier as in `values-fr` or `raw-fr`.
+const RESOURCE_TYPES: [&str; 14] = [
+	"anim",
+	"animator",
+	"color",
+	"drawable",
+	"font",
+	"interpolator",
+	"layout",
+	"menu",
+	"mipmap",
+	"navigation",
+	"raw",
+	"transition",
+	"values",
+	"xml",
+];
+
+impl Language {
+	/// Parse an Android resource qualifier such as `values-zh-rTW`, `pt-rBR` or `b+sr+Latn`.
+	///
+	/// The `values-` (or any other resource type, like `raw-` or `xml-`) prefix is optional, as are
+	/// the `mcc`/`mnc` qualifiers Android puts before the locale; qualifiers after the locale, such
+	/// as `-night`, are ignored. The locale resolves through [`Language::closest`], so
+	/// legacy codes (`iw`, `in`, `ji`) work and `b+sr+Latn` gives [`Language::SrLatnSp`].
+	pub fn from_android_qualifier(qualifier: &str) -> Result<Self> {
+		let unsupported = || Error::UnsupportedAndroidQualifier(qualifier.into());
+		let mut parts = qualifier.trim().split('-').peekable();
+
+		if parts.peek().is_some_and(|part| RESOURCE_TYPES.contains(part)) {
+			parts.next();
+		}
+
+		// Mobile country and network codes, such as `mcc310-mnc004`.
+		while parts.peek().is_some_and(|part| {
+			part.strip_prefix("mcc")
+				.or_else(|| part.strip_prefix("mnc"))
+				.is_some_and(|code| !code.is_empty() && code.bytes().all(|b| b.is_ascii_digit()))
+		}) {
+			parts.next();
+		}
+
+		let tag = match parts.next() {
+			Some(bcp47) if bcp47.starts_with("b+") => bcp47[2..].replace('+', "-"),
+			Some(language) if language.len() == 2 || language.len() == 3 => {
+				match parts.next().and_then(|region| region.strip_prefix(['r', 'R'])) {
+					Some(region) if region.len() == 2 => format!("{language}-{region}"),
+					_ => language.into(),
+				}
+			},
+			_ => return Err(unsupported()),
+		};
+
+		Self::closest(&tag).map(|closest| closest.language()).map_err(|_| unsupported())
+	}
+
+	/// Format as an Android resource qualifier without the `values-` prefix.
+	///
+	/// Tags with a 2-letter language and an optional 2-letter region use the legacy form
+	/// (`pt-rBR`); anything else, like scripts, 3-letter languages or `419`-style regions, uses
+	/// the BCP 47 form (`b+sr+Latn`). Deprecated regions are written with their preferred value
+	/// (`SP` → `RS`).
+	pub fn android_qualifier(&self) -> String {
+		let subtags = self
+			.tag()
+			.split('-')
+			.map(|subtag| alias::preferred_region(subtag).unwrap_or(subtag))
+			.collect::<Vec<_>>();
+
+		match subtags[..] {
+			[language] if language.len() == 2 => language.into(),
+			[language, region]
+				if language.len() == 2
+					&& region.len() == 2
+					&& region.bytes().all(|b| b.is_ascii_alphabetic()) =>
+				format!("{language}-r{region}"),
+			_ => format!("b+{}", subtags.join("+")),
+		}
+	}
+}
@@ -0,0 +1,58 @@
+// self
+use crate::{alias, prelude::*};
+
+// Chinese variants Apple names by script, as in `zh-Hans.lproj`.
+const CHINESE_SCRIPTS: [(&str, &str); 4] =
+	[("zh-CN", "zh-Hans"), ("zh-SG", "zh-Hans-SG"), ("zh-TW", "zh-Hant"), ("zh-MO", "zh-Hant-MO")];
+// Legacy `.lproj` folder names from before Xcode used language codes.
+const LEGACY_NAMES: [(&str, &str); 7] = [
+	("Dutch", "nl"),
+	("English", "en"),
+	("French", "fr"),
+	("German", "de"),
+	("Italian", "it"),
+	("Japanese", "ja"),
+	("Spanish", "es"),
+];
+
+impl Language {
+	/// Parse an Apple locale identifier or `.lproj` folder name such as `pt-BR.lproj`, `zh_TW` or
+	/// `English.lproj`.
+	///
+	/// The identifier resolves through [`Language::closest`], so `zh-Hans-SG` gives
+	/// [`Language::ZhSg`] and `sr-Latn` gives [`Language::SrLatnSp`].
+	pub fn from_apple_locale(locale: &str) -> Result<Self> {
+		let unsupported = || Error::UnsupportedAppleLocale(locale.into());
+		let name = locale.trim();
+		let name = name.strip_suffix(".lproj").unwrap_or(name);
+		let tag = LEGACY_NAMES
+			.into_iter()
+			.find(|(legacy, _)| *legacy == name)
+			.map_or(name, |(_, tag)| tag);
+
+		Self::closest(tag).map(|closest| closest.language()).map_err(|_| unsupported())
+	}
+
+	/// Format as an Apple locale identifier, as used for `.lproj` folders and `Localizable` files.
+	///
+	/// Mainland, Singapore, Taiwan and Macau Chinese are named by script (`zh-Hans`, `zh-Hans-SG`,
+	/// `zh-Hant`, `zh-Hant-MO`) while `zh-HK` keeps its region, following Xcode. Deprecated regions
+	/// are written with their preferred value (`SP` → `RS`).
+	pub fn apple_locale(&self) -> String {
+		let tag = self.tag();
+
+		if let Some((_, apple)) = CHINESE_SCRIPTS.into_iter().find(|(chinese, _)| *chinese == tag) {
+			return apple.into();
+		}
+
+		tag.split('-')
+			.map(|subtag| alias::preferred_region(subtag).unwrap_or(subtag))
+			.collect::<Vec<_>>()
+			.join("-")
+	}
+
+	/// Get the `.lproj` folder name, such as `pt-BR.lproj`.
+	pub fn lproj(&self) -> String {
+		format!("{}.lproj", self.apple_locale())
+	}
+}
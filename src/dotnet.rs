@@ -0,0 +1,54 @@
+// self
+use crate::{alias, matching, prelude::*};
+
+// Subtags of .NET Framework 1.x and 2.0 culture names that differ from BCP 47.
+const LEGACY_SUBTAGS: [(&str, &str); 4] =
+	[("CHS", "Hans"), ("CHT", "Hant"), ("div", "dv"), ("qut", "quc")];
+
+impl Language {
+	/// Parse a .NET culture name such as `zh-TW`, `zh-CHS`, `sr-Latn-CS` or `sr-SP-Latn`.
+	///
+	/// Legacy names are accepted: `zh-CHS`/`zh-CHT`, the .NET 1.x region-before-script order and
+	/// alternate sort suffixes (`es-ES_tradnl`). Names without a variant that has the same likely
+	/// subtags fail with [`Error::UnsupportedDotnetCulture`], as does the invariant culture (`""`).
+	pub fn from_dotnet_culture(name: &str) -> Result<Self> {
+		let unsupported = || Error::UnsupportedDotnetCulture(name.into());
+		let culture = name.trim().split_once('_').map_or(name.trim(), |(culture, _)| culture);
+
+		if culture.is_empty() {
+			return Err(unsupported());
+		}
+
+		let mut subtags = culture
+			.split('-')
+			.map(|subtag| {
+				LEGACY_SUBTAGS
+					.into_iter()
+					.find(|(legacy, _)| legacy.eq_ignore_ascii_case(subtag))
+					.map_or(subtag, |(_, bcp47)| bcp47)
+			})
+			.collect::<Vec<_>>();
+
+		// .NET 1.x wrote the script last, as in `uz-UZ-Cyrl`.
+		if let [_, region, script] = &mut subtags[..]
+			&& region.len() == 2
+			&& script.len() == 4
+		{
+			std::mem::swap(region, script);
+		}
+
+		matching::equivalent(&subtags.join("-")).ok_or_else(unsupported)
+	}
+
+	/// Format as a .NET culture name, such as `zh-TW` or `sr-Latn-RS`.
+	///
+	/// Modern names are used: `zh-Hans` rather than `zh-CHS`, and deprecated regions are written
+	/// with their preferred value (`SP` → `RS`).
+	pub fn dotnet_culture(&self) -> String {
+		self.tag()
+			.split('-')
+			.map(|subtag| alias::preferred_region(subtag).unwrap_or(subtag))
+			.collect::<Vec<_>>()
+			.join("-")
+	}
+}
@@ -1,5 +1,8 @@
 #![allow(missing_docs)]
 
+// self
+use crate::plural::PluralCategory;
+
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 #[derive(Debug, thiserror::Error)]
@@ -7,6 +10,63 @@ pub enum Error {
 	/// The language tag is not supported by this crate.
 	#[error("Unsupported language tag `{0}`.")]
 	UnsupportedLanguageTag(String),
+	/// The string is not a well-formed RFC 5646 language tag.
+	#[error("Invalid language tag `{0}`.")]
+	InvalidLanguageTag(String),
+	/// The POSIX locale identifier does not map to a supported language tag.
+	#[error("Unsupported POSIX locale `{0}`.")]
+	UnsupportedPosixLocale(String),
+	/// The Android resource qualifier does not map to a supported language tag.
+	#[error("Unsupported Android resource qualifier `{0}`.")]
+	UnsupportedAndroidQualifier(String),
+	/// The Apple locale identifier does not map to a supported language tag.
+	#[error("Unsupported Apple locale `{0}`.")]
+	UnsupportedAppleLocale(String),
+	/// The Java locale string has no equivalent supported language tag.
+	#[error("Unsupported Java locale `{0}`.")]
+	UnsupportedJavaLocale(String),
+	/// The .NET culture name has no equivalent supported language tag.
+	#[error("Unsupported .NET culture `{0}`.")]
+	UnsupportedDotnetCulture(String),
+	/// No segment of the path is a supported language tag.
+	#[error("No language in path `{0}`.")]
+	NoLanguageInPath(String),
+	/// The ISO 639 code does not map to a supported language.
+	#[error("Unsupported ISO 639 code `{0}`.")]
+	UnsupportedIsoCode(String),
+	/// The Windows locale identifier does not map to a supported language tag.
+	#[error("Unsupported LCID `{0:#06X}`.")]
+	UnsupportedLcid(u32),
+	/// The plural category keyword is not a CLDR plural category.
+	#[error("Unsupported plural category `{0}`.")]
+	UnsupportedPluralCategory(String),
+	/// The number cannot be converted to CLDR plural operands.
+	#[error("Invalid plural operands `{0}`.")]
+	InvalidPluralOperands(String),
+	/// The CLDR plural rule cannot be parsed.
+	#[error("Invalid plural rule `{0}`.")]
+	InvalidPluralRule(String),
+	/// A set of CLDR plural rules repeats a category or does not end with `other`.
+	#[error("Plural rules must list each category once and end with `other`.")]
+	MalformedPluralRules,
+	/// A plural rule sample selects a different category than the rule that lists it.
+	#[error("Plural sample `{sample}` selects `{actual}` instead of `{expected}`.")]
+	PluralSampleMismatch { sample: String, expected: PluralCategory, actual: PluralCategory },
+	/// The MessageFormat pattern cannot be parsed.
+	#[error("Invalid message format `{0}`.")]
+	InvalidMessageFormat(String),
+	/// The MessageFormat argument is not provided.
+	#[error("Missing message argument `{0}`.")]
+	MissingMessageArgument(String),
+	/// The MessageFormat argument has the wrong type for its placeholder.
+	#[error("Invalid message argument `{0}`.")]
+	InvalidMessageArgument(String),
+	/// The gettext `Plural-Forms` header cannot be parsed.
+	#[error("Invalid gettext plural forms `{0}`.")]
+	InvalidPluralForms(String),
+	/// The gettext `plural` expression has no valid form index for the number.
+	#[error("gettext plural expression has no valid form for `{0}`.")]
+	PluralFormsEvaluation(u64),
 
 	/// Parsing an ICU locale failed.
 	#[cfg(feature = "icu_locale_core")]
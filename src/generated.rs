@@ -3,6 +3,690 @@ use std::convert::TryFrom;
 // self
 use crate::prelude::*;
 use Language::*;
+use PluralCategory::*;
+
+// CLDR likely subtags, sorted by source for binary search.
+#[rustfmt::skip]
+pub(crate) const LIKELY_SUBTAGS: [(&str, &str); 315] = [
+	("af", "af-Latn-ZA"),
+	("ak", "ak-Latn-GH"),
+	("am", "am-Ethi-ET"),
+	("ar", "ar-Arab-EG"),
+	("arn", "arn-Latn-CL"),
+	("as", "as-Beng-IN"),
+	("az", "az-Latn-AZ"),
+	("az-Arab", "az-Arab-IR"),
+	("az-IQ", "az-Arab-IQ"),
+	("az-IR", "az-Arab-IR"),
+	("az-RU", "az-Cyrl-RU"),
+	("ba", "ba-Cyrl-RU"),
+	("be", "be-Cyrl-BY"),
+	("bg", "bg-Cyrl-BG"),
+	("bm", "bm-Latn-ML"),
+	("bn", "bn-Beng-BD"),
+	("bo", "bo-Tibt-CN"),
+	("br", "br-Latn-FR"),
+	("bs", "bs-Latn-BA"),
+	("ca", "ca-Latn-ES"),
+	("co", "co-Latn-FR"),
+	("cs", "cs-Latn-CZ"),
+	("cy", "cy-Latn-GB"),
+	("da", "da-Latn-DK"),
+	("de", "de-Latn-DE"),
+	("dsb", "dsb-Latn-DE"),
+	("dv", "dv-Thaa-MV"),
+	("ee", "ee-Latn-GH"),
+	("el", "el-Grek-GR"),
+	("en", "en-Latn-US"),
+	("eo", "eo-Latn-001"),
+	("es", "es-Latn-ES"),
+	("et", "et-Latn-EE"),
+	("eu", "eu-Latn-ES"),
+	("fa", "fa-Arab-IR"),
+	("fi", "fi-Latn-FI"),
+	("fil", "fil-Latn-PH"),
+	("fo", "fo-Latn-FO"),
+	("fr", "fr-Latn-FR"),
+	("fy", "fy-Latn-NL"),
+	("ga", "ga-Latn-IE"),
+	("gd", "gd-Latn-GB"),
+	("gl", "gl-Latn-ES"),
+	("gn", "gn-Latn-PY"),
+	("gsw", "gsw-Latn-CH"),
+	("gu", "gu-Gujr-IN"),
+	("ha", "ha-Latn-NG"),
+	("ha-CM", "ha-Arab-CM"),
+	("ha-SD", "ha-Arab-SD"),
+	("he", "he-Hebr-IL"),
+	("hi", "hi-Deva-IN"),
+	("hmn", "hmn-Latn-CN"),
+	("hr", "hr-Latn-HR"),
+	("hsb", "hsb-Latn-DE"),
+	("ht", "ht-Latn-HT"),
+	("hu", "hu-Latn-HU"),
+	("hy", "hy-Armn-AM"),
+	("id", "id-Latn-ID"),
+	("ig", "ig-Latn-NG"),
+	("ii", "ii-Yiii-CN"),
+	("is", "is-Latn-IS"),
+	("it", "it-Latn-IT"),
+	("iu", "iu-Cans-CA"),
+	("iu-Latn", "iu-Latn-CA"),
+	("ja", "ja-Jpan-JP"),
+	("jv", "jv-Latn-ID"),
+	("ka", "ka-Geor-GE"),
+	("kg", "kg-Latn-CD"),
+	("ki", "ki-Latn-KE"),
+	("kk", "kk-Cyrl-KZ"),
+	("kl", "kl-Latn-GL"),
+	("km", "km-Khmr-KH"),
+	("kn", "kn-Knda-IN"),
+	("ko", "ko-Kore-KR"),
+	("kok", "kok-Deva-IN"),
+	("kr", "kr-Latn-NG"),
+	("ks", "ks-Arab-IN"),
+	("ks-Deva", "ks-Deva-IN"),
+	("ky", "ky-Cyrl-KG"),
+	("lb", "lb-Latn-LU"),
+	("lg", "lg-Latn-UG"),
+	("ln", "ln-Latn-CD"),
+	("lo", "lo-Laoo-LA"),
+	("lt", "lt-Latn-LT"),
+	("lv", "lv-Latn-LV"),
+	("mg", "mg-Latn-MG"),
+	("mi", "mi-Latn-NZ"),
+	("mk", "mk-Cyrl-MK"),
+	("ml", "ml-Mlym-IN"),
+	("mn", "mn-Cyrl-MN"),
+	("mn-CN", "mn-Mong-CN"),
+	("mn-Mong", "mn-Mong-CN"),
+	("moh", "moh-Latn-CA"),
+	("mr", "mr-Deva-IN"),
+	("ms", "ms-Latn-MY"),
+	("mt", "mt-Latn-MT"),
+	("my", "my-Mymr-MM"),
+	("nb", "nb-Latn-NO"),
+	("ne", "ne-Deva-NP"),
+	("nl", "nl-Latn-NL"),
+	("nn", "nn-Latn-NO"),
+	("no", "no-Latn-NO"),
+	("nso", "nso-Latn-ZA"),
+	("ny", "ny-Latn-MW"),
+	("oc", "oc-Latn-FR"),
+	("om", "om-Latn-ET"),
+	("or", "or-Orya-IN"),
+	("pa", "pa-Guru-IN"),
+	("pa-Arab", "pa-Arab-PK"),
+	("pa-PK", "pa-Arab-PK"),
+	("pl", "pl-Latn-PL"),
+	("prs", "prs-Arab-AF"),
+	("ps", "ps-Arab-AF"),
+	("pt", "pt-Latn-BR"),
+	("qu", "qu-Latn-PE"),
+	("quc", "quc-Latn-GT"),
+	("rm", "rm-Latn-CH"),
+	("rn", "rn-Latn-BI"),
+	("ro", "ro-Latn-RO"),
+	("ru", "ru-Cyrl-RU"),
+	("rw", "rw-Latn-RW"),
+	("sa", "sa-Deva-IN"),
+	("sah", "sah-Cyrl-RU"),
+	("sc", "sc-Latn-IT"),
+	("sd", "sd-Arab-PK"),
+	("sd-Deva", "sd-Deva-IN"),
+	("se", "se-Latn-NO"),
+	("si", "si-Sinh-LK"),
+	("sk", "sk-Latn-SK"),
+	("sl", "sl-Latn-SI"),
+	("sn", "sn-Latn-ZW"),
+	("so", "so-Latn-SO"),
+	("sq", "sq-Latn-AL"),
+	("sr", "sr-Cyrl-RS"),
+	("sr-ME", "sr-Latn-ME"),
+	("sr-RO", "sr-Latn-RO"),
+	("sr-TR", "sr-Latn-TR"),
+	("st", "st-Latn-ZA"),
+	("su", "su-Latn-ID"),
+	("sv", "sv-Latn-SE"),
+	("sw", "sw-Latn-TZ"),
+	("syr", "syr-Syrc-IQ"),
+	("ta", "ta-Taml-IN"),
+	("te", "te-Telu-IN"),
+	("tg", "tg-Cyrl-TJ"),
+	("th", "th-Thai-TH"),
+	("ti", "ti-Ethi-ET"),
+	("tk", "tk-Latn-TM"),
+	("tl", "tl-Latn-PH"),
+	("tn", "tn-Latn-ZA"),
+	("tr", "tr-Latn-TR"),
+	("ts", "ts-Latn-ZA"),
+	("tt", "tt-Cyrl-RU"),
+	("tzm", "tzm-Latn-MA"),
+	("ug", "ug-Arab-CN"),
+	("uk", "uk-Cyrl-UA"),
+	("und", "en-Latn-US"),
+	("und-AE", "ar-Arab-AE"),
+	("und-AL", "sq-Latn-AL"),
+	("und-AM", "hy-Armn-AM"),
+	("und-AR", "es-Latn-AR"),
+	("und-AT", "de-Latn-AT"),
+	("und-AU", "en-Latn-AU"),
+	("und-AZ", "az-Latn-AZ"),
+	("und-Arab", "ar-Arab-EG"),
+	("und-Armn", "hy-Armn-AM"),
+	("und-BA", "bs-Latn-BA"),
+	("und-BD", "bn-Beng-BD"),
+	("und-BE", "nl-Latn-BE"),
+	("und-BG", "bg-Cyrl-BG"),
+	("und-BH", "ar-Arab-BH"),
+	("und-BN", "ms-Latn-BN"),
+	("und-BO", "es-Latn-BO"),
+	("und-BR", "pt-Latn-BR"),
+	("und-BY", "be-Cyrl-BY"),
+	("und-BZ", "en-Latn-BZ"),
+	("und-Beng", "bn-Beng-BD"),
+	("und-CA", "en-Latn-CA"),
+	("und-CH", "de-Latn-CH"),
+	("und-CL", "es-Latn-CL"),
+	("und-CN", "zh-Hans-CN"),
+	("und-CO", "es-Latn-CO"),
+	("und-CR", "es-Latn-CR"),
+	("und-CZ", "cs-Latn-CZ"),
+	("und-Cans", "iu-Cans-CA"),
+	("und-Cyrl", "ru-Cyrl-RU"),
+	("und-DE", "de-Latn-DE"),
+	("und-DK", "da-Latn-DK"),
+	("und-DO", "es-Latn-DO"),
+	("und-DZ", "ar-Arab-DZ"),
+	("und-Deva", "hi-Deva-IN"),
+	("und-EC", "es-Latn-EC"),
+	("und-EE", "et-Latn-EE"),
+	("und-EG", "ar-Arab-EG"),
+	("und-ES", "es-Latn-ES"),
+	("und-Ethi", "am-Ethi-ET"),
+	("und-FI", "fi-Latn-FI"),
+	("und-FR", "fr-Latn-FR"),
+	("und-GB", "en-Latn-GB"),
+	("und-GE", "ka-Geor-GE"),
+	("und-GR", "el-Grek-GR"),
+	("und-GT", "es-Latn-GT"),
+	("und-Geor", "ka-Geor-GE"),
+	("und-Grek", "el-Grek-GR"),
+	("und-Gujr", "gu-Gujr-IN"),
+	("und-Guru", "pa-Guru-IN"),
+	("und-HK", "zh-Hant-HK"),
+	("und-HN", "es-Latn-HN"),
+	("und-HR", "hr-Latn-HR"),
+	("und-HU", "hu-Latn-HU"),
+	("und-Hans", "zh-Hans-CN"),
+	("und-Hant", "zh-Hant-TW"),
+	("und-Hebr", "he-Hebr-IL"),
+	("und-ID", "id-Latn-ID"),
+	("und-IE", "en-Latn-IE"),
+	("und-IL", "he-Hebr-IL"),
+	("und-IN", "hi-Deva-IN"),
+	("und-IQ", "ar-Arab-IQ"),
+	("und-IR", "fa-Arab-IR"),
+	("und-IS", "is-Latn-IS"),
+	("und-IT", "it-Latn-IT"),
+	("und-JM", "en-Latn-JM"),
+	("und-JO", "ar-Arab-JO"),
+	("und-JP", "ja-Jpan-JP"),
+	("und-Jpan", "ja-Jpan-JP"),
+	("und-KR", "ko-Kore-KR"),
+	("und-KW", "ar-Arab-KW"),
+	("und-KZ", "ru-Cyrl-KZ"),
+	("und-Khmr", "km-Khmr-KH"),
+	("und-Knda", "kn-Knda-IN"),
+	("und-Kore", "ko-Kore-KR"),
+	("und-LB", "ar-Arab-LB"),
+	("und-LI", "de-Latn-LI"),
+	("und-LT", "lt-Latn-LT"),
+	("und-LU", "fr-Latn-LU"),
+	("und-LV", "lv-Latn-LV"),
+	("und-LY", "ar-Arab-LY"),
+	("und-Laoo", "lo-Laoo-LA"),
+	("und-Latn", "en-Latn-US"),
+	("und-MA", "ar-Arab-MA"),
+	("und-MC", "fr-Latn-MC"),
+	("und-MK", "mk-Cyrl-MK"),
+	("und-MN", "mn-Cyrl-MN"),
+	("und-MO", "zh-Hant-MO"),
+	("und-MX", "es-Latn-MX"),
+	("und-MY", "ms-Latn-MY"),
+	("und-Mlym", "ml-Mlym-IN"),
+	("und-Mong", "mn-Mong-CN"),
+	("und-Mymr", "my-Mymr-MM"),
+	("und-NI", "es-Latn-NI"),
+	("und-NL", "nl-Latn-NL"),
+	("und-NO", "nb-Latn-NO"),
+	("und-NZ", "en-Latn-NZ"),
+	("und-OM", "ar-Arab-OM"),
+	("und-Orya", "or-Orya-IN"),
+	("und-PA", "es-Latn-PA"),
+	("und-PE", "es-Latn-PE"),
+	("und-PH", "fil-Latn-PH"),
+	("und-PK", "ur-Arab-PK"),
+	("und-PL", "pl-Latn-PL"),
+	("und-PR", "es-Latn-PR"),
+	("und-PT", "pt-Latn-PT"),
+	("und-PY", "gn-Latn-PY"),
+	("und-QA", "ar-Arab-QA"),
+	("und-RO", "ro-Latn-RO"),
+	("und-RS", "sr-Cyrl-RS"),
+	("und-RU", "ru-Cyrl-RU"),
+	("und-SA", "ar-Arab-SA"),
+	("und-SE", "sv-Latn-SE"),
+	("und-SG", "en-Latn-SG"),
+	("und-SI", "sl-Latn-SI"),
+	("und-SK", "sk-Latn-SK"),
+	("und-SV", "es-Latn-SV"),
+	("und-SY", "ar-Arab-SY"),
+	("und-Sinh", "si-Sinh-LK"),
+	("und-Syrc", "syr-Syrc-IQ"),
+	("und-TH", "th-Thai-TH"),
+	("und-TN", "ar-Arab-TN"),
+	("und-TR", "tr-Latn-TR"),
+	("und-TT", "en-Latn-TT"),
+	("und-TW", "zh-Hant-TW"),
+	("und-Taml", "ta-Taml-IN"),
+	("und-Telu", "te-Telu-IN"),
+	("und-Thaa", "dv-Thaa-MV"),
+	("und-Thai", "th-Thai-TH"),
+	("und-Tibt", "bo-Tibt-CN"),
+	("und-UA", "uk-Cyrl-UA"),
+	("und-US", "en-Latn-US"),
+	("und-UY", "es-Latn-UY"),
+	("und-UZ", "uz-Latn-UZ"),
+	("und-VE", "es-Latn-VE"),
+	("und-VN", "vi-Latn-VN"),
+	("und-YE", "ar-Arab-YE"),
+	("und-Yiii", "ii-Yiii-CN"),
+	("und-ZA", "en-Latn-ZA"),
+	("und-ZW", "sn-Latn-ZW"),
+	("ur", "ur-Arab-PK"),
+	("uz", "uz-Latn-UZ"),
+	("uz-AF", "uz-Arab-AF"),
+	("uz-Arab", "uz-Arab-AF"),
+	("uz-CN", "uz-Cyrl-CN"),
+	("ve", "ve-Latn-ZA"),
+	("vi", "vi-Latn-VN"),
+	("wo", "wo-Latn-SN"),
+	("xh", "xh-Latn-ZA"),
+	("yi", "yi-Hebr-UA"),
+	("yo", "yo-Latn-NG"),
+	("zh", "zh-Hans-CN"),
+	("zh-AU", "zh-Hant-AU"),
+	("zh-HK", "zh-Hant-HK"),
+	("zh-Hant", "zh-Hant-TW"),
+	("zh-MO", "zh-Hant-MO"),
+	("zh-TW", "zh-Hant-TW"),
+	("zh-US", "zh-Hant-US"),
+	("zu", "zu-Latn-ZA"),
+];
+// Windows LCIDs, sorted by LCID for binary search.
+#[rustfmt::skip]
+pub(crate) const LCIDS: [(u32, &str); 359] = [
+	(0x0001, "ar"),
+	(0x0002, "bg"),
+	(0x0003, "ca"),
+	(0x0004, "zh-Hans"),
+	(0x0005, "cs"),
+	(0x0006, "da"),
+	(0x0007, "de"),
+	(0x0008, "el"),
+	(0x0009, "en"),
+	(0x000A, "es"),
+	(0x000B, "fi"),
+	(0x000C, "fr"),
+	(0x000D, "he"),
+	(0x000E, "hu"),
+	(0x000F, "is"),
+	(0x0010, "it"),
+	(0x0011, "ja"),
+	(0x0012, "ko"),
+	(0x0013, "nl"),
+	(0x0014, "no"),
+	(0x0015, "pl"),
+	(0x0016, "pt"),
+	(0x0017, "rm"),
+	(0x0018, "ro"),
+	(0x0019, "ru"),
+	(0x001A, "hr"),
+	(0x001B, "sk"),
+	(0x001C, "sq"),
+	(0x001D, "sv"),
+	(0x001E, "th"),
+	(0x001F, "tr"),
+	(0x0020, "ur"),
+	(0x0021, "id"),
+	(0x0022, "uk"),
+	(0x0023, "be"),
+	(0x0024, "sl"),
+	(0x0025, "et"),
+	(0x0026, "lv"),
+	(0x0027, "lt"),
+	(0x0028, "tg"),
+	(0x0029, "fa"),
+	(0x002A, "vi"),
+	(0x002B, "hy"),
+	(0x002C, "az"),
+	(0x002D, "eu"),
+	(0x002E, "hsb"),
+	(0x002F, "mk"),
+	(0x0030, "st"),
+	(0x0031, "ts"),
+	(0x0032, "tn"),
+	(0x0033, "ve"),
+	(0x0034, "xh"),
+	(0x0035, "zu"),
+	(0x0036, "af"),
+	(0x0037, "ka"),
+	(0x0038, "fo"),
+	(0x0039, "hi"),
+	(0x003A, "mt"),
+	(0x003B, "se"),
+	(0x003C, "ga"),
+	(0x003E, "ms"),
+	(0x003F, "kk"),
+	(0x0040, "ky"),
+	(0x0041, "sw"),
+	(0x0042, "tk"),
+	(0x0043, "uz"),
+	(0x0044, "tt"),
+	(0x0045, "bn"),
+	(0x0046, "pa"),
+	(0x0047, "gu"),
+	(0x0048, "or"),
+	(0x0049, "ta"),
+	(0x004A, "te"),
+	(0x004B, "kn"),
+	(0x004C, "ml"),
+	(0x004D, "as"),
+	(0x004E, "mr"),
+	(0x004F, "sa"),
+	(0x0050, "mn"),
+	(0x0051, "bo"),
+	(0x0052, "cy"),
+	(0x0053, "km"),
+	(0x0054, "lo"),
+	(0x0055, "my"),
+	(0x0056, "gl"),
+	(0x0057, "kok"),
+	(0x0059, "sd"),
+	(0x005A, "syr"),
+	(0x005B, "si"),
+	(0x005D, "iu"),
+	(0x005E, "am"),
+	(0x005F, "tzm"),
+	(0x0060, "ks"),
+	(0x0061, "ne"),
+	(0x0062, "fy"),
+	(0x0063, "ps"),
+	(0x0064, "fil"),
+	(0x0065, "dv"),
+	(0x0068, "ha"),
+	(0x006A, "yo"),
+	(0x006C, "nso"),
+	(0x006D, "ba"),
+	(0x006E, "lb"),
+	(0x006F, "kl"),
+	(0x0070, "ig"),
+	(0x0071, "kr"),
+	(0x0072, "om"),
+	(0x0073, "ti"),
+	(0x0074, "gn"),
+	(0x0077, "so"),
+	(0x0078, "ii"),
+	(0x007A, "arn"),
+	(0x007C, "moh"),
+	(0x007E, "br"),
+	(0x0080, "ug"),
+	(0x0081, "mi"),
+	(0x0082, "oc"),
+	(0x0083, "co"),
+	(0x0084, "gsw"),
+	(0x0085, "sah"),
+	(0x0086, "quc"),
+	(0x0087, "rw"),
+	(0x0088, "wo"),
+	(0x008C, "prs"),
+	(0x0091, "gd"),
+	(0x0401, "ar-SA"),
+	(0x0402, "bg-BG"),
+	(0x0403, "ca-ES"),
+	(0x0404, "zh-TW"),
+	(0x0405, "cs-CZ"),
+	(0x0406, "da-DK"),
+	(0x0407, "de-DE"),
+	(0x0408, "el-GR"),
+	(0x0409, "en-US"),
+	(0x040B, "fi-FI"),
+	(0x040C, "fr-FR"),
+	(0x040D, "he-IL"),
+	(0x040E, "hu-HU"),
+	(0x040F, "is-IS"),
+	(0x0410, "it-IT"),
+	(0x0411, "ja-JP"),
+	(0x0412, "ko-KR"),
+	(0x0413, "nl-NL"),
+	(0x0414, "nb-NO"),
+	(0x0415, "pl-PL"),
+	(0x0416, "pt-BR"),
+	(0x0417, "rm-CH"),
+	(0x0418, "ro-RO"),
+	(0x0419, "ru-RU"),
+	(0x041A, "hr-HR"),
+	(0x041B, "sk-SK"),
+	(0x041C, "sq-AL"),
+	(0x041D, "sv-SE"),
+	(0x041E, "th-TH"),
+	(0x041F, "tr-TR"),
+	(0x0420, "ur-PK"),
+	(0x0421, "id-ID"),
+	(0x0422, "uk-UA"),
+	(0x0423, "be-BY"),
+	(0x0424, "sl-SI"),
+	(0x0425, "et-EE"),
+	(0x0426, "lv-LV"),
+	(0x0427, "lt-LT"),
+	(0x0428, "tg-Cyrl-TJ"),
+	(0x0429, "fa-IR"),
+	(0x042A, "vi-VN"),
+	(0x042B, "hy-AM"),
+	(0x042C, "az-Latn-AZ"),
+	(0x042D, "eu-ES"),
+	(0x042E, "hsb-DE"),
+	(0x042F, "mk-MK"),
+	(0x0430, "st-ZA"),
+	(0x0431, "ts-ZA"),
+	(0x0432, "tn-ZA"),
+	(0x0433, "ve-ZA"),
+	(0x0434, "xh-ZA"),
+	(0x0435, "zu-ZA"),
+	(0x0436, "af-ZA"),
+	(0x0437, "ka-GE"),
+	(0x0438, "fo-FO"),
+	(0x0439, "hi-IN"),
+	(0x043A, "mt-MT"),
+	(0x043B, "se-NO"),
+	(0x043E, "ms-MY"),
+	(0x043F, "kk-KZ"),
+	(0x0440, "ky-KG"),
+	(0x0441, "sw-KE"),
+	(0x0442, "tk-TM"),
+	(0x0443, "uz-Latn-UZ"),
+	(0x0444, "tt-RU"),
+	(0x0445, "bn-IN"),
+	(0x0446, "pa-IN"),
+	(0x0447, "gu-IN"),
+	(0x0448, "or-IN"),
+	(0x0449, "ta-IN"),
+	(0x044A, "te-IN"),
+	(0x044B, "kn-IN"),
+	(0x044C, "ml-IN"),
+	(0x044D, "as-IN"),
+	(0x044E, "mr-IN"),
+	(0x044F, "sa-IN"),
+	(0x0450, "mn-MN"),
+	(0x0451, "bo-CN"),
+	(0x0452, "cy-GB"),
+	(0x0453, "km-KH"),
+	(0x0454, "lo-LA"),
+	(0x0455, "my-MM"),
+	(0x0456, "gl-ES"),
+	(0x0457, "kok-IN"),
+	(0x045A, "syr-SY"),
+	(0x045B, "si-LK"),
+	(0x045D, "iu-Cans-CA"),
+	(0x045E, "am-ET"),
+	(0x0460, "ks-Arab"),
+	(0x0461, "ne-NP"),
+	(0x0462, "fy-NL"),
+	(0x0463, "ps-AF"),
+	(0x0464, "fil-PH"),
+	(0x0465, "dv-MV"),
+	(0x0468, "ha-Latn-NG"),
+	(0x046A, "yo-NG"),
+	(0x046C, "nso-ZA"),
+	(0x046D, "ba-RU"),
+	(0x046E, "lb-LU"),
+	(0x046F, "kl-GL"),
+	(0x0470, "ig-NG"),
+	(0x0471, "kr-Latn-NG"),
+	(0x0472, "om-ET"),
+	(0x0473, "ti-ET"),
+	(0x0474, "gn-PY"),
+	(0x0477, "so-SO"),
+	(0x0478, "ii-CN"),
+	(0x047A, "arn-CL"),
+	(0x047C, "moh-CA"),
+	(0x047E, "br-FR"),
+	(0x0480, "ug-CN"),
+	(0x0481, "mi-NZ"),
+	(0x0482, "oc-FR"),
+	(0x0483, "co-FR"),
+	(0x0484, "gsw-FR"),
+	(0x0485, "sah-RU"),
+	(0x0486, "quc-Latn-GT"),
+	(0x0487, "rw-RW"),
+	(0x0488, "wo-SN"),
+	(0x048C, "prs-AF"),
+	(0x0491, "gd-GB"),
+	(0x0801, "ar-IQ"),
+	(0x0804, "zh-CN"),
+	(0x0807, "de-CH"),
+	(0x0809, "en-GB"),
+	(0x080A, "es-MX"),
+	(0x080C, "fr-BE"),
+	(0x0810, "it-CH"),
+	(0x0813, "nl-BE"),
+	(0x0814, "nn-NO"),
+	(0x0816, "pt-PT"),
+	(0x081A, "sr-Latn-SP"),
+	(0x081D, "sv-FI"),
+	(0x0820, "ur-IN"),
+	(0x082C, "az-Cyrl-AZ"),
+	(0x082E, "dsb-DE"),
+	(0x0832, "tn-BW"),
+	(0x083B, "se-SE"),
+	(0x083C, "ga-IE"),
+	(0x083E, "ms-BN"),
+	(0x0843, "uz-Cyrl-UZ"),
+	(0x0845, "bn-BD"),
+	(0x0846, "pa-Arab-PK"),
+	(0x0849, "ta-LK"),
+	(0x0850, "mn-Mong-CN"),
+	(0x0859, "sd-Arab-PK"),
+	(0x085D, "iu-Latn-CA"),
+	(0x085F, "tzm-Latn-DZ"),
+	(0x0873, "ti-ER"),
+	(0x0C01, "ar-EG"),
+	(0x0C04, "zh-HK"),
+	(0x0C07, "de-AT"),
+	(0x0C09, "en-AU"),
+	(0x0C0A, "es-ES"),
+	(0x0C0C, "fr-CA"),
+	(0x0C1A, "sr-Cyrl-SP"),
+	(0x0C3B, "se-FI"),
+	(0x1001, "ar-LY"),
+	(0x1004, "zh-SG"),
+	(0x1007, "de-LU"),
+	(0x1009, "en-CA"),
+	(0x100A, "es-GT"),
+	(0x100C, "fr-CH"),
+	(0x101A, "hr-BA"),
+	(0x1401, "ar-DZ"),
+	(0x1404, "zh-MO"),
+	(0x1407, "de-LI"),
+	(0x1409, "en-NZ"),
+	(0x140A, "es-CR"),
+	(0x140C, "fr-LU"),
+	(0x141A, "bs-Latn-BA"),
+	(0x1801, "ar-MA"),
+	(0x1809, "en-IE"),
+	(0x180A, "es-PA"),
+	(0x180C, "fr-MC"),
+	(0x181A, "sr-Latn-BA"),
+	(0x1C01, "ar-TN"),
+	(0x1C09, "en-ZA"),
+	(0x1C0A, "es-DO"),
+	(0x1C1A, "sr-Cyrl-BA"),
+	(0x2001, "ar-OM"),
+	(0x2009, "en-JM"),
+	(0x200A, "es-VE"),
+	(0x201A, "bs-Cyrl-BA"),
+	(0x2401, "ar-YE"),
+	(0x2409, "en-029"),
+	(0x240A, "es-CO"),
+	(0x241A, "sr-Latn-RS"),
+	(0x2801, "ar-SY"),
+	(0x2809, "en-BZ"),
+	(0x280A, "es-PE"),
+	(0x281A, "sr-Cyrl-RS"),
+	(0x2C01, "ar-JO"),
+	(0x2C09, "en-TT"),
+	(0x2C0A, "es-AR"),
+	(0x2C1A, "sr-Latn-ME"),
+	(0x3001, "ar-LB"),
+	(0x3009, "en-ZW"),
+	(0x300A, "es-EC"),
+	(0x301A, "sr-Cyrl-ME"),
+	(0x3401, "ar-KW"),
+	(0x3409, "en-PH"),
+	(0x340A, "es-CL"),
+	(0x3801, "ar-AE"),
+	(0x380A, "es-UY"),
+	(0x3C01, "ar-BH"),
+	(0x3C0A, "es-PY"),
+	(0x4001, "ar-QA"),
+	(0x4009, "en-IN"),
+	(0x400A, "es-BO"),
+	(0x4409, "en-MY"),
+	(0x440A, "es-SV"),
+	(0x4809, "en-SG"),
+	(0x480A, "es-HN"),
+	(0x4C0A, "es-NI"),
+	(0x500A, "es-PR"),
+	(0x540A, "es-US"),
+	(0x641A, "bs-Cyrl"),
+	(0x681A, "bs-Latn"),
+	(0x6C1A, "sr-Cyrl"),
+	(0x701A, "sr-Latn"),
+	(0x742C, "az-Cyrl"),
+	(0x7804, "zh"),
+	(0x7814, "nn"),
+	(0x781A, "bs"),
+	(0x782C, "az-Latn"),
+	(0x7843, "uz-Cyrl"),
+	(0x7850, "mn-Cyrl"),
+	(0x7C04, "zh-Hant"),
+	(0x7C14, "nb"),
+	(0x7C1A, "sr"),
+	(0x7C2E, "dsb"),
+	(0x7C43, "uz-Latn"),
+	(0x7C50, "mn-Mong"),
+];
 
 /// Generated from the translation.io languages-with-plural-cases page.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -1491,6 +2175,2999 @@ impl Language {
 			Zu => "isiZulu",
 		}
 	}
+
+	/// Get the CLDR plural categories used by cardinal numbers in this language.
+	pub fn plural_categories(&self) -> &'static [PluralCategory] {
+		match self {
+			Af => &[One, Other],
+			Ak => &[One, Other],
+			Am => &[One, Other],
+			Ar => &[Zero, One, Two, Few, Many, Other],
+			ArAe => &[Zero, One, Two, Few, Many, Other],
+			ArBh => &[Zero, One, Two, Few, Many, Other],
+			ArDz => &[Zero, One, Two, Few, Many, Other],
+			ArEg => &[Zero, One, Two, Few, Many, Other],
+			ArIq => &[Zero, One, Two, Few, Many, Other],
+			ArJo => &[Zero, One, Two, Few, Many, Other],
+			ArKw => &[Zero, One, Two, Few, Many, Other],
+			ArLb => &[Zero, One, Two, Few, Many, Other],
+			ArLy => &[Zero, One, Two, Few, Many, Other],
+			ArMa => &[Zero, One, Two, Few, Many, Other],
+			ArOm => &[Zero, One, Two, Few, Many, Other],
+			ArQa => &[Zero, One, Two, Few, Many, Other],
+			ArSa => &[Zero, One, Two, Few, Many, Other],
+			ArSy => &[Zero, One, Two, Few, Many, Other],
+			ArTn => &[Zero, One, Two, Few, Many, Other],
+			ArYe => &[Zero, One, Two, Few, Many, Other],
+			Arn => &[Other],
+			As => &[One, Other],
+			Az => &[One, Other],
+			AzCyrlAz => &[One, Other],
+			AzLatnAz => &[One, Other],
+			Ba => &[Other],
+			Be => &[One, Few, Many, Other],
+			Bg => &[One, Other],
+			Bm => &[Other],
+			Bn => &[One, Other],
+			BnBd => &[One, Other],
+			BnIn => &[One, Other],
+			Bo => &[Other],
+			Br => &[One, Two, Few, Many, Other],
+			Bs => &[One, Few, Other],
+			BsCyrl => &[One, Few, Other],
+			BsLatn => &[One, Few, Other],
+			Ca => &[One, Many, Other],
+			Co => &[Other],
+			Cs => &[One, Few, Many, Other],
+			Cy => &[Zero, One, Two, Few, Many, Other],
+			Da => &[One, Other],
+			De => &[One, Other],
+			DeAt => &[One, Other],
+			DeCh => &[One, Other],
+			DeDe => &[One, Other],
+			DeLi => &[One, Other],
+			DeLu => &[One, Other],
+			Dsb => &[One, Two, Few, Other],
+			Dv => &[One, Other],
+			Ee => &[One, Other],
+			El => &[One, Other],
+			En => &[One, Other],
+			En029 => &[One, Other],
+			EnAu => &[One, Other],
+			EnBz => &[One, Other],
+			EnCa => &[One, Other],
+			EnGb => &[One, Other],
+			EnIe => &[One, Other],
+			EnIn => &[One, Other],
+			EnJm => &[One, Other],
+			EnMy => &[One, Other],
+			EnNz => &[One, Other],
+			EnPh => &[One, Other],
+			EnSg => &[One, Other],
+			EnTt => &[One, Other],
+			EnUs => &[One, Other],
+			EnZa => &[One, Other],
+			EnZw => &[One, Other],
+			Eo => &[One, Other],
+			Es => &[One, Many, Other],
+			EsAr => &[One, Many, Other],
+			EsBo => &[One, Many, Other],
+			EsCl => &[One, Many, Other],
+			EsCo => &[One, Many, Other],
+			EsCr => &[One, Many, Other],
+			EsDo => &[One, Many, Other],
+			EsEc => &[One, Many, Other],
+			EsEs => &[One, Many, Other],
+			EsGt => &[One, Many, Other],
+			EsHn => &[One, Many, Other],
+			EsMx => &[One, Many, Other],
+			EsNi => &[One, Many, Other],
+			EsPa => &[One, Many, Other],
+			EsPe => &[One, Many, Other],
+			EsPr => &[One, Many, Other],
+			EsPy => &[One, Many, Other],
+			EsSv => &[One, Many, Other],
+			EsUs => &[One, Many, Other],
+			EsUy => &[One, Many, Other],
+			EsVe => &[One, Many, Other],
+			Et => &[One, Other],
+			Eu => &[One, Other],
+			Fa => &[One, Other],
+			Fi => &[One, Other],
+			Fil => &[One, Other],
+			Fo => &[One, Other],
+			Fr => &[One, Many, Other],
+			FrBe => &[One, Many, Other],
+			FrCa => &[One, Many, Other],
+			FrCh => &[One, Many, Other],
+			FrFr => &[One, Many, Other],
+			FrLu => &[One, Many, Other],
+			FrMc => &[One, Many, Other],
+			Fy => &[One, Other],
+			Ga => &[One, Two, Few, Many, Other],
+			Gd => &[One, Two, Few, Other],
+			Gl => &[One, Other],
+			Gn => &[Other],
+			Gsw => &[One, Other],
+			Gu => &[One, Other],
+			Ha => &[One, Other],
+			He => &[One, Two, Other],
+			Hi => &[One, Other],
+			Hmn => &[Other],
+			Hr => &[One, Few, Other],
+			HrBa => &[One, Few, Other],
+			HrHr => &[One, Few, Other],
+			Hsb => &[One, Two, Few, Other],
+			Ht => &[Other],
+			Hu => &[One, Other],
+			Hy => &[One, Other],
+			Id => &[Other],
+			Ig => &[Other],
+			Ii => &[Other],
+			Is => &[One, Other],
+			It => &[One, Many, Other],
+			ItCh => &[One, Many, Other],
+			ItIt => &[One, Many, Other],
+			Iu => &[One, Two, Other],
+			Ja => &[Other],
+			Jv => &[Other],
+			Ka => &[One, Other],
+			Kg => &[Other],
+			Ki => &[Other],
+			Kk => &[One, Other],
+			Kl => &[One, Other],
+			Km => &[Other],
+			Kn => &[One, Other],
+			Ko => &[Other],
+			Kok => &[Other],
+			Kr => &[Other],
+			Ks => &[One, Other],
+			Ky => &[One, Other],
+			Lb => &[One, Other],
+			Lg => &[One, Other],
+			Ln => &[One, Other],
+			Lo => &[Other],
+			Lt => &[One, Few, Many, Other],
+			Lv => &[Zero, One, Other],
+			Mg => &[One, Other],
+			Mi => &[Other],
+			Mk => &[One, Other],
+			Ml => &[One, Other],
+			Mn => &[One, Other],
+			MnMn => &[One, Other],
+			MnMongCn => &[One, Other],
+			Moh => &[Other],
+			Mr => &[One, Other],
+			Ms => &[Other],
+			MsBn => &[Other],
+			MsMy => &[Other],
+			Mt => &[One, Two, Few, Many, Other],
+			My => &[Other],
+			Nb => &[One, Other],
+			NbNo => &[One, Other],
+			Ne => &[One, Other],
+			Nl => &[One, Other],
+			NlBe => &[One, Other],
+			NlNl => &[One, Other],
+			Nn => &[One, Other],
+			No => &[One, Other],
+			Nso => &[One, Other],
+			Ny => &[One, Other],
+			Oc => &[Other],
+			Om => &[One, Other],
+			Or => &[One, Other],
+			Pa => &[One, Other],
+			Pl => &[One, Few, Many, Other],
+			Prs => &[Other],
+			Ps => &[One, Other],
+			Pt => &[One, Many, Other],
+			PtBr => &[One, Many, Other],
+			PtPt => &[One, Many, Other],
+			Qu => &[Other],
+			Quc => &[Other],
+			Rm => &[One, Other],
+			Rn => &[Other],
+			Ro => &[One, Few, Other],
+			Ru => &[One, Few, Many, Other],
+			Rw => &[Other],
+			Sa => &[Other],
+			Sah => &[Other],
+			Sc => &[One, Other],
+			Sd => &[One, Other],
+			Se => &[One, Two, Other],
+			Si => &[One, Other],
+			Sk => &[One, Few, Many, Other],
+			Sl => &[One, Two, Few, Other],
+			Sn => &[One, Other],
+			So => &[One, Other],
+			Sq => &[One, Other],
+			Sr => &[One, Few, Other],
+			SrCyrlSp => &[One, Few, Other],
+			SrLatnSp => &[One, Few, Other],
+			St => &[One, Other],
+			Su => &[Other],
+			Sv => &[One, Other],
+			SvFi => &[One, Other],
+			SvSe => &[One, Other],
+			Sw => &[One, Other],
+			Syr => &[One, Other],
+			Ta => &[One, Other],
+			Te => &[One, Other],
+			Tg => &[Other],
+			Th => &[Other],
+			Ti => &[One, Other],
+			Tk => &[One, Other],
+			Tl => &[One, Other],
+			Tn => &[One, Other],
+			Tr => &[One, Other],
+			Ts => &[One, Other],
+			Tt => &[Other],
+			Tzm => &[One, Other],
+			Ug => &[One, Other],
+			Uk => &[One, Few, Many, Other],
+			Ur => &[One, Other],
+			Uz => &[One, Other],
+			UzCyrlUz => &[One, Other],
+			UzLatnUz => &[One, Other],
+			Ve => &[One, Other],
+			Vi => &[Other],
+			Wo => &[Other],
+			Xh => &[One, Other],
+			Yo => &[Other],
+			ZhCn => &[Other],
+			ZhHk => &[Other],
+			ZhHans => &[Other],
+			ZhHant => &[Other],
+			ZhMo => &[Other],
+			ZhSg => &[Other],
+			ZhTw => &[Other],
+			Zu => &[One, Other],
+		}
+	}
+
+	/// Select the CLDR cardinal plural category for an integer.
+	pub fn plural_category(&self, n: u64) -> PluralCategory {
+		self.plural_category_for(&PluralOperands::from(n))
+	}
+
+	/// Select the CLDR cardinal plural category for a formatted number.
+	pub fn plural_category_for(&self, operands: &PluralOperands) -> PluralCategory {
+		match self {
+			Af => cardinal_af(operands),
+			Ak => cardinal_ak(operands),
+			Am => cardinal_am(operands),
+			Ar => cardinal_ar(operands),
+			ArAe => cardinal_ar(operands),
+			ArBh => cardinal_ar(operands),
+			ArDz => cardinal_ar(operands),
+			ArEg => cardinal_ar(operands),
+			ArIq => cardinal_ar(operands),
+			ArJo => cardinal_ar(operands),
+			ArKw => cardinal_ar(operands),
+			ArLb => cardinal_ar(operands),
+			ArLy => cardinal_ar(operands),
+			ArMa => cardinal_ar(operands),
+			ArOm => cardinal_ar(operands),
+			ArQa => cardinal_ar(operands),
+			ArSa => cardinal_ar(operands),
+			ArSy => cardinal_ar(operands),
+			ArTn => cardinal_ar(operands),
+			ArYe => cardinal_ar(operands),
+			Arn => cardinal_root(operands),
+			As => cardinal_am(operands),
+			Az => cardinal_af(operands),
+			AzCyrlAz => cardinal_af(operands),
+			AzLatnAz => cardinal_af(operands),
+			Ba => cardinal_root(operands),
+			Be => cardinal_be(operands),
+			Bg => cardinal_af(operands),
+			Bm => cardinal_bm(operands),
+			Bn => cardinal_am(operands),
+			BnBd => cardinal_am(operands),
+			BnIn => cardinal_am(operands),
+			Bo => cardinal_bm(operands),
+			Br => cardinal_br(operands),
+			Bs => cardinal_bs(operands),
+			BsCyrl => cardinal_bs(operands),
+			BsLatn => cardinal_bs(operands),
+			Ca => cardinal_ca(operands),
+			Co => cardinal_root(operands),
+			Cs => cardinal_cs(operands),
+			Cy => cardinal_cy(operands),
+			Da => cardinal_da(operands),
+			De => cardinal_de(operands),
+			DeAt => cardinal_de(operands),
+			DeCh => cardinal_de(operands),
+			DeDe => cardinal_de(operands),
+			DeLi => cardinal_de(operands),
+			DeLu => cardinal_de(operands),
+			Dsb => cardinal_dsb(operands),
+			Dv => cardinal_af(operands),
+			Ee => cardinal_af(operands),
+			El => cardinal_af(operands),
+			En => cardinal_de(operands),
+			En029 => cardinal_de(operands),
+			EnAu => cardinal_de(operands),
+			EnBz => cardinal_de(operands),
+			EnCa => cardinal_de(operands),
+			EnGb => cardinal_de(operands),
+			EnIe => cardinal_de(operands),
+			EnIn => cardinal_de(operands),
+			EnJm => cardinal_de(operands),
+			EnMy => cardinal_de(operands),
+			EnNz => cardinal_de(operands),
+			EnPh => cardinal_de(operands),
+			EnSg => cardinal_de(operands),
+			EnTt => cardinal_de(operands),
+			EnUs => cardinal_de(operands),
+			EnZa => cardinal_de(operands),
+			EnZw => cardinal_de(operands),
+			Eo => cardinal_af(operands),
+			Es => cardinal_es(operands),
+			EsAr => cardinal_es(operands),
+			EsBo => cardinal_es(operands),
+			EsCl => cardinal_es(operands),
+			EsCo => cardinal_es(operands),
+			EsCr => cardinal_es(operands),
+			EsDo => cardinal_es(operands),
+			EsEc => cardinal_es(operands),
+			EsEs => cardinal_es(operands),
+			EsGt => cardinal_es(operands),
+			EsHn => cardinal_es(operands),
+			EsMx => cardinal_es(operands),
+			EsNi => cardinal_es(operands),
+			EsPa => cardinal_es(operands),
+			EsPe => cardinal_es(operands),
+			EsPr => cardinal_es(operands),
+			EsPy => cardinal_es(operands),
+			EsSv => cardinal_es(operands),
+			EsUs => cardinal_es(operands),
+			EsUy => cardinal_es(operands),
+			EsVe => cardinal_es(operands),
+			Et => cardinal_de(operands),
+			Eu => cardinal_af(operands),
+			Fa => cardinal_am(operands),
+			Fi => cardinal_de(operands),
+			Fil => cardinal_fil(operands),
+			Fo => cardinal_af(operands),
+			Fr => cardinal_fr(operands),
+			FrBe => cardinal_fr(operands),
+			FrCa => cardinal_fr(operands),
+			FrCh => cardinal_fr(operands),
+			FrFr => cardinal_fr(operands),
+			FrLu => cardinal_fr(operands),
+			FrMc => cardinal_fr(operands),
+			Fy => cardinal_de(operands),
+			Ga => cardinal_ga(operands),
+			Gd => cardinal_gd(operands),
+			Gl => cardinal_de(operands),
+			Gn => cardinal_root(operands),
+			Gsw => cardinal_af(operands),
+			Gu => cardinal_am(operands),
+			Ha => cardinal_af(operands),
+			He => cardinal_he(operands),
+			Hi => cardinal_am(operands),
+			Hmn => cardinal_root(operands),
+			Hr => cardinal_bs(operands),
+			HrBa => cardinal_bs(operands),
+			HrHr => cardinal_bs(operands),
+			Hsb => cardinal_dsb(operands),
+			Ht => cardinal_root(operands),
+			Hu => cardinal_af(operands),
+			Hy => cardinal_hy(operands),
+			Id => cardinal_bm(operands),
+			Ig => cardinal_bm(operands),
+			Ii => cardinal_bm(operands),
+			Is => cardinal_is(operands),
+			It => cardinal_ca(operands),
+			ItCh => cardinal_ca(operands),
+			ItIt => cardinal_ca(operands),
+			Iu => cardinal_iu(operands),
+			Ja => cardinal_bm(operands),
+			Jv => cardinal_bm(operands),
+			Ka => cardinal_af(operands),
+			Kg => cardinal_root(operands),
+			Ki => cardinal_root(operands),
+			Kk => cardinal_af(operands),
+			Kl => cardinal_af(operands),
+			Km => cardinal_bm(operands),
+			Kn => cardinal_am(operands),
+			Ko => cardinal_bm(operands),
+			Kok => cardinal_root(operands),
+			Kr => cardinal_root(operands),
+			Ks => cardinal_af(operands),
+			Ky => cardinal_af(operands),
+			Lb => cardinal_af(operands),
+			Lg => cardinal_af(operands),
+			Ln => cardinal_ak(operands),
+			Lo => cardinal_bm(operands),
+			Lt => cardinal_lt(operands),
+			Lv => cardinal_lv(operands),
+			Mg => cardinal_ak(operands),
+			Mi => cardinal_root(operands),
+			Mk => cardinal_mk(operands),
+			Ml => cardinal_af(operands),
+			Mn => cardinal_af(operands),
+			MnMn => cardinal_af(operands),
+			MnMongCn => cardinal_af(operands),
+			Moh => cardinal_root(operands),
+			Mr => cardinal_af(operands),
+			Ms => cardinal_bm(operands),
+			MsBn => cardinal_bm(operands),
+			MsMy => cardinal_bm(operands),
+			Mt => cardinal_mt(operands),
+			My => cardinal_bm(operands),
+			Nb => cardinal_af(operands),
+			NbNo => cardinal_af(operands),
+			Ne => cardinal_af(operands),
+			Nl => cardinal_de(operands),
+			NlBe => cardinal_de(operands),
+			NlNl => cardinal_de(operands),
+			Nn => cardinal_af(operands),
+			No => cardinal_af(operands),
+			Nso => cardinal_ak(operands),
+			Ny => cardinal_af(operands),
+			Oc => cardinal_root(operands),
+			Om => cardinal_af(operands),
+			Or => cardinal_af(operands),
+			Pa => cardinal_ak(operands),
+			Pl => cardinal_pl(operands),
+			Prs => cardinal_root(operands),
+			Ps => cardinal_af(operands),
+			Pt => cardinal_pt(operands),
+			PtBr => cardinal_pt(operands),
+			PtPt => cardinal_ca(operands),
+			Qu => cardinal_root(operands),
+			Quc => cardinal_root(operands),
+			Rm => cardinal_af(operands),
+			Rn => cardinal_root(operands),
+			Ro => cardinal_ro(operands),
+			Ru => cardinal_ru(operands),
+			Rw => cardinal_root(operands),
+			Sa => cardinal_root(operands),
+			Sah => cardinal_bm(operands),
+			Sc => cardinal_de(operands),
+			Sd => cardinal_af(operands),
+			Se => cardinal_iu(operands),
+			Si => cardinal_si(operands),
+			Sk => cardinal_cs(operands),
+			Sl => cardinal_sl(operands),
+			Sn => cardinal_af(operands),
+			So => cardinal_af(operands),
+			Sq => cardinal_af(operands),
+			Sr => cardinal_bs(operands),
+			SrCyrlSp => cardinal_bs(operands),
+			SrLatnSp => cardinal_bs(operands),
+			St => cardinal_af(operands),
+			Su => cardinal_bm(operands),
+			Sv => cardinal_de(operands),
+			SvFi => cardinal_de(operands),
+			SvSe => cardinal_de(operands),
+			Sw => cardinal_de(operands),
+			Syr => cardinal_af(operands),
+			Ta => cardinal_af(operands),
+			Te => cardinal_af(operands),
+			Tg => cardinal_root(operands),
+			Th => cardinal_bm(operands),
+			Ti => cardinal_ak(operands),
+			Tk => cardinal_af(operands),
+			Tl => cardinal_fil(operands),
+			Tn => cardinal_af(operands),
+			Tr => cardinal_af(operands),
+			Ts => cardinal_af(operands),
+			Tt => cardinal_root(operands),
+			Tzm => cardinal_tzm(operands),
+			Ug => cardinal_af(operands),
+			Uk => cardinal_ru(operands),
+			Ur => cardinal_de(operands),
+			Uz => cardinal_af(operands),
+			UzCyrlUz => cardinal_af(operands),
+			UzLatnUz => cardinal_af(operands),
+			Ve => cardinal_af(operands),
+			Vi => cardinal_bm(operands),
+			Wo => cardinal_bm(operands),
+			Xh => cardinal_af(operands),
+			Yo => cardinal_bm(operands),
+			ZhCn => cardinal_bm(operands),
+			ZhHk => cardinal_bm(operands),
+			ZhHans => cardinal_bm(operands),
+			ZhHant => cardinal_bm(operands),
+			ZhMo => cardinal_bm(operands),
+			ZhSg => cardinal_bm(operands),
+			ZhTw => cardinal_bm(operands),
+			Zu => cardinal_am(operands),
+		}
+	}
+
+	/// Get the CLDR sample numbers of a cardinal category, or `None` when the language does not
+	/// use it.
+	pub fn plural_samples(&self, category: PluralCategory) -> Option<PluralSamples> {
+		match self {
+			Af => samples_af(category),
+			Ak => samples_ak(category),
+			Am => samples_am(category),
+			Ar => samples_ar(category),
+			ArAe => samples_ar(category),
+			ArBh => samples_ar(category),
+			ArDz => samples_ar(category),
+			ArEg => samples_ar(category),
+			ArIq => samples_ar(category),
+			ArJo => samples_ar(category),
+			ArKw => samples_ar(category),
+			ArLb => samples_ar(category),
+			ArLy => samples_ar(category),
+			ArMa => samples_ar(category),
+			ArOm => samples_ar(category),
+			ArQa => samples_ar(category),
+			ArSa => samples_ar(category),
+			ArSy => samples_ar(category),
+			ArTn => samples_ar(category),
+			ArYe => samples_ar(category),
+			Arn => samples_root(category),
+			As => samples_am(category),
+			Az => samples_af(category),
+			AzCyrlAz => samples_af(category),
+			AzLatnAz => samples_af(category),
+			Ba => samples_root(category),
+			Be => samples_be(category),
+			Bg => samples_af(category),
+			Bm => samples_bm(category),
+			Bn => samples_am(category),
+			BnBd => samples_am(category),
+			BnIn => samples_am(category),
+			Bo => samples_bm(category),
+			Br => samples_br(category),
+			Bs => samples_bs(category),
+			BsCyrl => samples_bs(category),
+			BsLatn => samples_bs(category),
+			Ca => samples_ca(category),
+			Co => samples_root(category),
+			Cs => samples_cs(category),
+			Cy => samples_cy(category),
+			Da => samples_da(category),
+			De => samples_de(category),
+			DeAt => samples_de(category),
+			DeCh => samples_de(category),
+			DeDe => samples_de(category),
+			DeLi => samples_de(category),
+			DeLu => samples_de(category),
+			Dsb => samples_dsb(category),
+			Dv => samples_af(category),
+			Ee => samples_af(category),
+			El => samples_af(category),
+			En => samples_de(category),
+			En029 => samples_de(category),
+			EnAu => samples_de(category),
+			EnBz => samples_de(category),
+			EnCa => samples_de(category),
+			EnGb => samples_de(category),
+			EnIe => samples_de(category),
+			EnIn => samples_de(category),
+			EnJm => samples_de(category),
+			EnMy => samples_de(category),
+			EnNz => samples_de(category),
+			EnPh => samples_de(category),
+			EnSg => samples_de(category),
+			EnTt => samples_de(category),
+			EnUs => samples_de(category),
+			EnZa => samples_de(category),
+			EnZw => samples_de(category),
+			Eo => samples_af(category),
+			Es => samples_es(category),
+			EsAr => samples_es(category),
+			EsBo => samples_es(category),
+			EsCl => samples_es(category),
+			EsCo => samples_es(category),
+			EsCr => samples_es(category),
+			EsDo => samples_es(category),
+			EsEc => samples_es(category),
+			EsEs => samples_es(category),
+			EsGt => samples_es(category),
+			EsHn => samples_es(category),
+			EsMx => samples_es(category),
+			EsNi => samples_es(category),
+			EsPa => samples_es(category),
+			EsPe => samples_es(category),
+			EsPr => samples_es(category),
+			EsPy => samples_es(category),
+			EsSv => samples_es(category),
+			EsUs => samples_es(category),
+			EsUy => samples_es(category),
+			EsVe => samples_es(category),
+			Et => samples_de(category),
+			Eu => samples_af(category),
+			Fa => samples_am(category),
+			Fi => samples_de(category),
+			Fil => samples_fil(category),
+			Fo => samples_af(category),
+			Fr => samples_fr(category),
+			FrBe => samples_fr(category),
+			FrCa => samples_fr(category),
+			FrCh => samples_fr(category),
+			FrFr => samples_fr(category),
+			FrLu => samples_fr(category),
+			FrMc => samples_fr(category),
+			Fy => samples_de(category),
+			Ga => samples_ga(category),
+			Gd => samples_gd(category),
+			Gl => samples_de(category),
+			Gn => samples_root(category),
+			Gsw => samples_af(category),
+			Gu => samples_am(category),
+			Ha => samples_af(category),
+			He => samples_he(category),
+			Hi => samples_am(category),
+			Hmn => samples_root(category),
+			Hr => samples_bs(category),
+			HrBa => samples_bs(category),
+			HrHr => samples_bs(category),
+			Hsb => samples_dsb(category),
+			Ht => samples_root(category),
+			Hu => samples_af(category),
+			Hy => samples_hy(category),
+			Id => samples_bm(category),
+			Ig => samples_bm(category),
+			Ii => samples_bm(category),
+			Is => samples_is(category),
+			It => samples_ca(category),
+			ItCh => samples_ca(category),
+			ItIt => samples_ca(category),
+			Iu => samples_iu(category),
+			Ja => samples_bm(category),
+			Jv => samples_bm(category),
+			Ka => samples_af(category),
+			Kg => samples_root(category),
+			Ki => samples_root(category),
+			Kk => samples_af(category),
+			Kl => samples_af(category),
+			Km => samples_bm(category),
+			Kn => samples_am(category),
+			Ko => samples_bm(category),
+			Kok => samples_root(category),
+			Kr => samples_root(category),
+			Ks => samples_af(category),
+			Ky => samples_af(category),
+			Lb => samples_af(category),
+			Lg => samples_af(category),
+			Ln => samples_ak(category),
+			Lo => samples_bm(category),
+			Lt => samples_lt(category),
+			Lv => samples_lv(category),
+			Mg => samples_ak(category),
+			Mi => samples_root(category),
+			Mk => samples_mk(category),
+			Ml => samples_af(category),
+			Mn => samples_af(category),
+			MnMn => samples_af(category),
+			MnMongCn => samples_af(category),
+			Moh => samples_root(category),
+			Mr => samples_af(category),
+			Ms => samples_bm(category),
+			MsBn => samples_bm(category),
+			MsMy => samples_bm(category),
+			Mt => samples_mt(category),
+			My => samples_bm(category),
+			Nb => samples_af(category),
+			NbNo => samples_af(category),
+			Ne => samples_af(category),
+			Nl => samples_de(category),
+			NlBe => samples_de(category),
+			NlNl => samples_de(category),
+			Nn => samples_af(category),
+			No => samples_af(category),
+			Nso => samples_ak(category),
+			Ny => samples_af(category),
+			Oc => samples_root(category),
+			Om => samples_af(category),
+			Or => samples_af(category),
+			Pa => samples_ak(category),
+			Pl => samples_pl(category),
+			Prs => samples_root(category),
+			Ps => samples_af(category),
+			Pt => samples_pt(category),
+			PtBr => samples_pt(category),
+			PtPt => samples_ca(category),
+			Qu => samples_root(category),
+			Quc => samples_root(category),
+			Rm => samples_af(category),
+			Rn => samples_root(category),
+			Ro => samples_ro(category),
+			Ru => samples_ru(category),
+			Rw => samples_root(category),
+			Sa => samples_root(category),
+			Sah => samples_bm(category),
+			Sc => samples_de(category),
+			Sd => samples_af(category),
+			Se => samples_iu(category),
+			Si => samples_si(category),
+			Sk => samples_cs(category),
+			Sl => samples_sl(category),
+			Sn => samples_af(category),
+			So => samples_af(category),
+			Sq => samples_af(category),
+			Sr => samples_bs(category),
+			SrCyrlSp => samples_bs(category),
+			SrLatnSp => samples_bs(category),
+			St => samples_af(category),
+			Su => samples_bm(category),
+			Sv => samples_de(category),
+			SvFi => samples_de(category),
+			SvSe => samples_de(category),
+			Sw => samples_de(category),
+			Syr => samples_af(category),
+			Ta => samples_af(category),
+			Te => samples_af(category),
+			Tg => samples_root(category),
+			Th => samples_bm(category),
+			Ti => samples_ak(category),
+			Tk => samples_af(category),
+			Tl => samples_fil(category),
+			Tn => samples_af(category),
+			Tr => samples_af(category),
+			Ts => samples_af(category),
+			Tt => samples_root(category),
+			Tzm => samples_tzm(category),
+			Ug => samples_af(category),
+			Uk => samples_ru(category),
+			Ur => samples_de(category),
+			Uz => samples_af(category),
+			UzCyrlUz => samples_af(category),
+			UzLatnUz => samples_af(category),
+			Ve => samples_af(category),
+			Vi => samples_bm(category),
+			Wo => samples_bm(category),
+			Xh => samples_af(category),
+			Yo => samples_bm(category),
+			ZhCn => samples_bm(category),
+			ZhHk => samples_bm(category),
+			ZhHans => samples_bm(category),
+			ZhHant => samples_bm(category),
+			ZhMo => samples_bm(category),
+			ZhSg => samples_bm(category),
+			ZhTw => samples_bm(category),
+			Zu => samples_am(category),
+		}
+	}
+
+	/// Get the canonical gettext `Plural-Forms` header value derived from the CLDR cardinal rules.
+	///
+	/// Plural form indices follow the categories of [`Self::plural_categories`] that have integer
+	/// samples, in CLDR order, so `ru` has `nplurals=3` for its 4 categories.
+	pub fn gettext_plural_forms(&self) -> &'static str {
+		match self {
+			Af => gettext_af(),
+			Ak => gettext_ak(),
+			Am => gettext_am(),
+			Ar => gettext_ar(),
+			ArAe => gettext_ar(),
+			ArBh => gettext_ar(),
+			ArDz => gettext_ar(),
+			ArEg => gettext_ar(),
+			ArIq => gettext_ar(),
+			ArJo => gettext_ar(),
+			ArKw => gettext_ar(),
+			ArLb => gettext_ar(),
+			ArLy => gettext_ar(),
+			ArMa => gettext_ar(),
+			ArOm => gettext_ar(),
+			ArQa => gettext_ar(),
+			ArSa => gettext_ar(),
+			ArSy => gettext_ar(),
+			ArTn => gettext_ar(),
+			ArYe => gettext_ar(),
+			Arn => gettext_root(),
+			As => gettext_am(),
+			Az => gettext_af(),
+			AzCyrlAz => gettext_af(),
+			AzLatnAz => gettext_af(),
+			Ba => gettext_root(),
+			Be => gettext_be(),
+			Bg => gettext_af(),
+			Bm => gettext_bm(),
+			Bn => gettext_am(),
+			BnBd => gettext_am(),
+			BnIn => gettext_am(),
+			Bo => gettext_bm(),
+			Br => gettext_br(),
+			Bs => gettext_bs(),
+			BsCyrl => gettext_bs(),
+			BsLatn => gettext_bs(),
+			Ca => gettext_ca(),
+			Co => gettext_root(),
+			Cs => gettext_cs(),
+			Cy => gettext_cy(),
+			Da => gettext_da(),
+			De => gettext_de(),
+			DeAt => gettext_de(),
+			DeCh => gettext_de(),
+			DeDe => gettext_de(),
+			DeLi => gettext_de(),
+			DeLu => gettext_de(),
+			Dsb => gettext_dsb(),
+			Dv => gettext_af(),
+			Ee => gettext_af(),
+			El => gettext_af(),
+			En => gettext_de(),
+			En029 => gettext_de(),
+			EnAu => gettext_de(),
+			EnBz => gettext_de(),
+			EnCa => gettext_de(),
+			EnGb => gettext_de(),
+			EnIe => gettext_de(),
+			EnIn => gettext_de(),
+			EnJm => gettext_de(),
+			EnMy => gettext_de(),
+			EnNz => gettext_de(),
+			EnPh => gettext_de(),
+			EnSg => gettext_de(),
+			EnTt => gettext_de(),
+			EnUs => gettext_de(),
+			EnZa => gettext_de(),
+			EnZw => gettext_de(),
+			Eo => gettext_af(),
+			Es => gettext_es(),
+			EsAr => gettext_es(),
+			EsBo => gettext_es(),
+			EsCl => gettext_es(),
+			EsCo => gettext_es(),
+			EsCr => gettext_es(),
+			EsDo => gettext_es(),
+			EsEc => gettext_es(),
+			EsEs => gettext_es(),
+			EsGt => gettext_es(),
+			EsHn => gettext_es(),
+			EsMx => gettext_es(),
+			EsNi => gettext_es(),
+			EsPa => gettext_es(),
+			EsPe => gettext_es(),
+			EsPr => gettext_es(),
+			EsPy => gettext_es(),
+			EsSv => gettext_es(),
+			EsUs => gettext_es(),
+			EsUy => gettext_es(),
+			EsVe => gettext_es(),
+			Et => gettext_de(),
+			Eu => gettext_af(),
+			Fa => gettext_am(),
+			Fi => gettext_de(),
+			Fil => gettext_fil(),
+			Fo => gettext_af(),
+			Fr => gettext_fr(),
+			FrBe => gettext_fr(),
+			FrCa => gettext_fr(),
+			FrCh => gettext_fr(),
+			FrFr => gettext_fr(),
+			FrLu => gettext_fr(),
+			FrMc => gettext_fr(),
+			Fy => gettext_de(),
+			Ga => gettext_ga(),
+			Gd => gettext_gd(),
+			Gl => gettext_de(),
+			Gn => gettext_root(),
+			Gsw => gettext_af(),
+			Gu => gettext_am(),
+			Ha => gettext_af(),
+			He => gettext_he(),
+			Hi => gettext_am(),
+			Hmn => gettext_root(),
+			Hr => gettext_bs(),
+			HrBa => gettext_bs(),
+			HrHr => gettext_bs(),
+			Hsb => gettext_dsb(),
+			Ht => gettext_root(),
+			Hu => gettext_af(),
+			Hy => gettext_hy(),
+			Id => gettext_bm(),
+			Ig => gettext_bm(),
+			Ii => gettext_bm(),
+			Is => gettext_is(),
+			It => gettext_ca(),
+			ItCh => gettext_ca(),
+			ItIt => gettext_ca(),
+			Iu => gettext_iu(),
+			Ja => gettext_bm(),
+			Jv => gettext_bm(),
+			Ka => gettext_af(),
+			Kg => gettext_root(),
+			Ki => gettext_root(),
+			Kk => gettext_af(),
+			Kl => gettext_af(),
+			Km => gettext_bm(),
+			Kn => gettext_am(),
+			Ko => gettext_bm(),
+			Kok => gettext_root(),
+			Kr => gettext_root(),
+			Ks => gettext_af(),
+			Ky => gettext_af(),
+			Lb => gettext_af(),
+			Lg => gettext_af(),
+			Ln => gettext_ak(),
+			Lo => gettext_bm(),
+			Lt => gettext_lt(),
+			Lv => gettext_lv(),
+			Mg => gettext_ak(),
+			Mi => gettext_root(),
+			Mk => gettext_mk(),
+			Ml => gettext_af(),
+			Mn => gettext_af(),
+			MnMn => gettext_af(),
+			MnMongCn => gettext_af(),
+			Moh => gettext_root(),
+			Mr => gettext_af(),
+			Ms => gettext_bm(),
+			MsBn => gettext_bm(),
+			MsMy => gettext_bm(),
+			Mt => gettext_mt(),
+			My => gettext_bm(),
+			Nb => gettext_af(),
+			NbNo => gettext_af(),
+			Ne => gettext_af(),
+			Nl => gettext_de(),
+			NlBe => gettext_de(),
+			NlNl => gettext_de(),
+			Nn => gettext_af(),
+			No => gettext_af(),
+			Nso => gettext_ak(),
+			Ny => gettext_af(),
+			Oc => gettext_root(),
+			Om => gettext_af(),
+			Or => gettext_af(),
+			Pa => gettext_ak(),
+			Pl => gettext_pl(),
+			Prs => gettext_root(),
+			Ps => gettext_af(),
+			Pt => gettext_pt(),
+			PtBr => gettext_pt(),
+			PtPt => gettext_ca(),
+			Qu => gettext_root(),
+			Quc => gettext_root(),
+			Rm => gettext_af(),
+			Rn => gettext_root(),
+			Ro => gettext_ro(),
+			Ru => gettext_ru(),
+			Rw => gettext_root(),
+			Sa => gettext_root(),
+			Sah => gettext_bm(),
+			Sc => gettext_de(),
+			Sd => gettext_af(),
+			Se => gettext_iu(),
+			Si => gettext_si(),
+			Sk => gettext_cs(),
+			Sl => gettext_sl(),
+			Sn => gettext_af(),
+			So => gettext_af(),
+			Sq => gettext_af(),
+			Sr => gettext_bs(),
+			SrCyrlSp => gettext_bs(),
+			SrLatnSp => gettext_bs(),
+			St => gettext_af(),
+			Su => gettext_bm(),
+			Sv => gettext_de(),
+			SvFi => gettext_de(),
+			SvSe => gettext_de(),
+			Sw => gettext_de(),
+			Syr => gettext_af(),
+			Ta => gettext_af(),
+			Te => gettext_af(),
+			Tg => gettext_root(),
+			Th => gettext_bm(),
+			Ti => gettext_ak(),
+			Tk => gettext_af(),
+			Tl => gettext_fil(),
+			Tn => gettext_af(),
+			Tr => gettext_af(),
+			Ts => gettext_af(),
+			Tt => gettext_root(),
+			Tzm => gettext_tzm(),
+			Ug => gettext_af(),
+			Uk => gettext_ru(),
+			Ur => gettext_de(),
+			Uz => gettext_af(),
+			UzCyrlUz => gettext_af(),
+			UzLatnUz => gettext_af(),
+			Ve => gettext_af(),
+			Vi => gettext_bm(),
+			Wo => gettext_bm(),
+			Xh => gettext_af(),
+			Yo => gettext_bm(),
+			ZhCn => gettext_bm(),
+			ZhHk => gettext_bm(),
+			ZhHans => gettext_bm(),
+			ZhHant => gettext_bm(),
+			ZhMo => gettext_bm(),
+			ZhSg => gettext_bm(),
+			ZhTw => gettext_bm(),
+			Zu => gettext_am(),
+		}
+	}
+
+	/// Get the CLDR plural categories used by ordinal numbers in this language.
+	pub fn ordinal_categories(&self) -> &'static [PluralCategory] {
+		match self {
+			Af => &[Other],
+			Ak => &[Other],
+			Am => &[Other],
+			Ar => &[Other],
+			ArAe => &[Other],
+			ArBh => &[Other],
+			ArDz => &[Other],
+			ArEg => &[Other],
+			ArIq => &[Other],
+			ArJo => &[Other],
+			ArKw => &[Other],
+			ArLb => &[Other],
+			ArLy => &[Other],
+			ArMa => &[Other],
+			ArOm => &[Other],
+			ArQa => &[Other],
+			ArSa => &[Other],
+			ArSy => &[Other],
+			ArTn => &[Other],
+			ArYe => &[Other],
+			Arn => &[Other],
+			As => &[One, Two, Few, Many, Other],
+			Az => &[One, Few, Many, Other],
+			AzCyrlAz => &[One, Few, Many, Other],
+			AzLatnAz => &[One, Few, Many, Other],
+			Ba => &[Other],
+			Be => &[Few, Other],
+			Bg => &[Other],
+			Bm => &[Other],
+			Bn => &[One, Two, Few, Many, Other],
+			BnBd => &[One, Two, Few, Many, Other],
+			BnIn => &[One, Two, Few, Many, Other],
+			Bo => &[Other],
+			Br => &[Other],
+			Bs => &[Other],
+			BsCyrl => &[Other],
+			BsLatn => &[Other],
+			Ca => &[One, Two, Few, Other],
+			Co => &[Other],
+			Cs => &[Other],
+			Cy => &[Zero, One, Two, Few, Many, Other],
+			Da => &[Other],
+			De => &[Other],
+			DeAt => &[Other],
+			DeCh => &[Other],
+			DeDe => &[Other],
+			DeLi => &[Other],
+			DeLu => &[Other],
+			Dsb => &[Other],
+			Dv => &[Other],
+			Ee => &[Other],
+			El => &[Other],
+			En => &[One, Two, Few, Other],
+			En029 => &[One, Two, Few, Other],
+			EnAu => &[One, Two, Few, Other],
+			EnBz => &[One, Two, Few, Other],
+			EnCa => &[One, Two, Few, Other],
+			EnGb => &[One, Two, Few, Other],
+			EnIe => &[One, Two, Few, Other],
+			EnIn => &[One, Two, Few, Other],
+			EnJm => &[One, Two, Few, Other],
+			EnMy => &[One, Two, Few, Other],
+			EnNz => &[One, Two, Few, Other],
+			EnPh => &[One, Two, Few, Other],
+			EnSg => &[One, Two, Few, Other],
+			EnTt => &[One, Two, Few, Other],
+			EnUs => &[One, Two, Few, Other],
+			EnZa => &[One, Two, Few, Other],
+			EnZw => &[One, Two, Few, Other],
+			Eo => &[Other],
+			Es => &[Other],
+			EsAr => &[Other],
+			EsBo => &[Other],
+			EsCl => &[Other],
+			EsCo => &[Other],
+			EsCr => &[Other],
+			EsDo => &[Other],
+			EsEc => &[Other],
+			EsEs => &[Other],
+			EsGt => &[Other],
+			EsHn => &[Other],
+			EsMx => &[Other],
+			EsNi => &[Other],
+			EsPa => &[Other],
+			EsPe => &[Other],
+			EsPr => &[Other],
+			EsPy => &[Other],
+			EsSv => &[Other],
+			EsUs => &[Other],
+			EsUy => &[Other],
+			EsVe => &[Other],
+			Et => &[Other],
+			Eu => &[Other],
+			Fa => &[Other],
+			Fi => &[Other],
+			Fil => &[One, Other],
+			Fo => &[Other],
+			Fr => &[One, Other],
+			FrBe => &[One, Other],
+			FrCa => &[One, Other],
+			FrCh => &[One, Other],
+			FrFr => &[One, Other],
+			FrLu => &[One, Other],
+			FrMc => &[One, Other],
+			Fy => &[Other],
+			Ga => &[One, Other],
+			Gd => &[One, Two, Few, Other],
+			Gl => &[Other],
+			Gn => &[Other],
+			Gsw => &[Other],
+			Gu => &[One, Two, Few, Many, Other],
+			Ha => &[Other],
+			He => &[Other],
+			Hi => &[One, Two, Few, Many, Other],
+			Hmn => &[Other],
+			Hr => &[Other],
+			HrBa => &[Other],
+			HrHr => &[Other],
+			Hsb => &[Other],
+			Ht => &[Other],
+			Hu => &[One, Other],
+			Hy => &[One, Other],
+			Id => &[Other],
+			Ig => &[Other],
+			Ii => &[Other],
+			Is => &[Other],
+			It => &[Many, Other],
+			ItCh => &[Many, Other],
+			ItIt => &[Many, Other],
+			Iu => &[Other],
+			Ja => &[Other],
+			Jv => &[Other],
+			Ka => &[One, Many, Other],
+			Kg => &[Other],
+			Ki => &[Other],
+			Kk => &[Many, Other],
+			Kl => &[Other],
+			Km => &[Other],
+			Kn => &[Other],
+			Ko => &[Other],
+			Kok => &[Other],
+			Kr => &[Other],
+			Ks => &[Other],
+			Ky => &[Other],
+			Lb => &[Other],
+			Lg => &[Other],
+			Ln => &[Other],
+			Lo => &[One, Other],
+			Lt => &[Other],
+			Lv => &[Other],
+			Mg => &[Other],
+			Mi => &[Other],
+			Mk => &[One, Two, Many, Other],
+			Ml => &[Other],
+			Mn => &[Other],
+			MnMn => &[Other],
+			MnMongCn => &[Other],
+			Moh => &[Other],
+			Mr => &[One, Two, Few, Other],
+			Ms => &[One, Other],
+			MsBn => &[One, Other],
+			MsMy => &[One, Other],
+			Mt => &[Other],
+			My => &[Other],
+			Nb => &[Other],
+			NbNo => &[Other],
+			Ne => &[One, Other],
+			Nl => &[Other],
+			NlBe => &[Other],
+			NlNl => &[Other],
+			Nn => &[Other],
+			No => &[Other],
+			Nso => &[Other],
+			Ny => &[Other],
+			Oc => &[Other],
+			Om => &[Other],
+			Or => &[One, Two, Few, Many, Other],
+			Pa => &[Other],
+			Pl => &[Other],
+			Prs => &[Other],
+			Ps => &[Other],
+			Pt => &[Other],
+			PtBr => &[Other],
+			PtPt => &[Other],
+			Qu => &[Other],
+			Quc => &[Other],
+			Rm => &[Other],
+			Rn => &[Other],
+			Ro => &[One, Other],
+			Ru => &[Other],
+			Rw => &[Other],
+			Sa => &[Other],
+			Sah => &[Other],
+			Sc => &[Many, Other],
+			Sd => &[Other],
+			Se => &[Other],
+			Si => &[Other],
+			Sk => &[Other],
+			Sl => &[Other],
+			Sn => &[Other],
+			So => &[Other],
+			Sq => &[One, Many, Other],
+			Sr => &[Other],
+			SrCyrlSp => &[Other],
+			SrLatnSp => &[Other],
+			St => &[Other],
+			Su => &[Other],
+			Sv => &[One, Other],
+			SvFi => &[One, Other],
+			SvSe => &[One, Other],
+			Sw => &[Other],
+			Syr => &[Other],
+			Ta => &[Other],
+			Te => &[Other],
+			Tg => &[Other],
+			Th => &[Other],
+			Ti => &[Other],
+			Tk => &[Few, Other],
+			Tl => &[One, Other],
+			Tn => &[Other],
+			Tr => &[Other],
+			Ts => &[Other],
+			Tt => &[Other],
+			Tzm => &[Other],
+			Ug => &[Other],
+			Uk => &[Few, Other],
+			Ur => &[Other],
+			Uz => &[Other],
+			UzCyrlUz => &[Other],
+			UzLatnUz => &[Other],
+			Ve => &[Other],
+			Vi => &[One, Other],
+			Wo => &[Other],
+			Xh => &[Other],
+			Yo => &[Other],
+			ZhCn => &[Other],
+			ZhHk => &[Other],
+			ZhHans => &[Other],
+			ZhHant => &[Other],
+			ZhMo => &[Other],
+			ZhSg => &[Other],
+			ZhTw => &[Other],
+			Zu => &[Other],
+		}
+	}
+
+	/// Select the CLDR ordinal plural category for a rank, such as `2` in "2nd".
+	pub fn ordinal_category(&self, n: u64) -> PluralCategory {
+		let operands = PluralOperands::from(n);
+
+		match self {
+			Af => ordinal_af(&operands),
+			Ak => ordinal_root(&operands),
+			Am => ordinal_af(&operands),
+			Ar => ordinal_af(&operands),
+			ArAe => ordinal_af(&operands),
+			ArBh => ordinal_af(&operands),
+			ArDz => ordinal_af(&operands),
+			ArEg => ordinal_af(&operands),
+			ArIq => ordinal_af(&operands),
+			ArJo => ordinal_af(&operands),
+			ArKw => ordinal_af(&operands),
+			ArLb => ordinal_af(&operands),
+			ArLy => ordinal_af(&operands),
+			ArMa => ordinal_af(&operands),
+			ArOm => ordinal_af(&operands),
+			ArQa => ordinal_af(&operands),
+			ArSa => ordinal_af(&operands),
+			ArSy => ordinal_af(&operands),
+			ArTn => ordinal_af(&operands),
+			ArYe => ordinal_af(&operands),
+			Arn => ordinal_root(&operands),
+			As => ordinal_as(&operands),
+			Az => ordinal_az(&operands),
+			AzCyrlAz => ordinal_az(&operands),
+			AzLatnAz => ordinal_az(&operands),
+			Ba => ordinal_root(&operands),
+			Be => ordinal_be(&operands),
+			Bg => ordinal_af(&operands),
+			Bm => ordinal_root(&operands),
+			Bn => ordinal_as(&operands),
+			BnBd => ordinal_as(&operands),
+			BnIn => ordinal_as(&operands),
+			Bo => ordinal_root(&operands),
+			Br => ordinal_root(&operands),
+			Bs => ordinal_af(&operands),
+			BsCyrl => ordinal_af(&operands),
+			BsLatn => ordinal_af(&operands),
+			Ca => ordinal_ca(&operands),
+			Co => ordinal_root(&operands),
+			Cs => ordinal_af(&operands),
+			Cy => ordinal_cy(&operands),
+			Da => ordinal_af(&operands),
+			De => ordinal_af(&operands),
+			DeAt => ordinal_af(&operands),
+			DeCh => ordinal_af(&operands),
+			DeDe => ordinal_af(&operands),
+			DeLi => ordinal_af(&operands),
+			DeLu => ordinal_af(&operands),
+			Dsb => ordinal_af(&operands),
+			Dv => ordinal_root(&operands),
+			Ee => ordinal_root(&operands),
+			El => ordinal_af(&operands),
+			En => ordinal_en(&operands),
+			En029 => ordinal_en(&operands),
+			EnAu => ordinal_en(&operands),
+			EnBz => ordinal_en(&operands),
+			EnCa => ordinal_en(&operands),
+			EnGb => ordinal_en(&operands),
+			EnIe => ordinal_en(&operands),
+			EnIn => ordinal_en(&operands),
+			EnJm => ordinal_en(&operands),
+			EnMy => ordinal_en(&operands),
+			EnNz => ordinal_en(&operands),
+			EnPh => ordinal_en(&operands),
+			EnSg => ordinal_en(&operands),
+			EnTt => ordinal_en(&operands),
+			EnUs => ordinal_en(&operands),
+			EnZa => ordinal_en(&operands),
+			EnZw => ordinal_en(&operands),
+			Eo => ordinal_root(&operands),
+			Es => ordinal_af(&operands),
+			EsAr => ordinal_af(&operands),
+			EsBo => ordinal_af(&operands),
+			EsCl => ordinal_af(&operands),
+			EsCo => ordinal_af(&operands),
+			EsCr => ordinal_af(&operands),
+			EsDo => ordinal_af(&operands),
+			EsEc => ordinal_af(&operands),
+			EsEs => ordinal_af(&operands),
+			EsGt => ordinal_af(&operands),
+			EsHn => ordinal_af(&operands),
+			EsMx => ordinal_af(&operands),
+			EsNi => ordinal_af(&operands),
+			EsPa => ordinal_af(&operands),
+			EsPe => ordinal_af(&operands),
+			EsPr => ordinal_af(&operands),
+			EsPy => ordinal_af(&operands),
+			EsSv => ordinal_af(&operands),
+			EsUs => ordinal_af(&operands),
+			EsUy => ordinal_af(&operands),
+			EsVe => ordinal_af(&operands),
+			Et => ordinal_af(&operands),
+			Eu => ordinal_af(&operands),
+			Fa => ordinal_af(&operands),
+			Fi => ordinal_af(&operands),
+			Fil => ordinal_fil(&operands),
+			Fo => ordinal_root(&operands),
+			Fr => ordinal_fil(&operands),
+			FrBe => ordinal_fil(&operands),
+			FrCa => ordinal_fil(&operands),
+			FrCh => ordinal_fil(&operands),
+			FrFr => ordinal_fil(&operands),
+			FrLu => ordinal_fil(&operands),
+			FrMc => ordinal_fil(&operands),
+			Fy => ordinal_af(&operands),
+			Ga => ordinal_fil(&operands),
+			Gd => ordinal_gd(&operands),
+			Gl => ordinal_af(&operands),
+			Gn => ordinal_root(&operands),
+			Gsw => ordinal_af(&operands),
+			Gu => ordinal_gu(&operands),
+			Ha => ordinal_root(&operands),
+			He => ordinal_af(&operands),
+			Hi => ordinal_gu(&operands),
+			Hmn => ordinal_root(&operands),
+			Hr => ordinal_af(&operands),
+			HrBa => ordinal_af(&operands),
+			HrHr => ordinal_af(&operands),
+			Hsb => ordinal_af(&operands),
+			Ht => ordinal_root(&operands),
+			Hu => ordinal_hu(&operands),
+			Hy => ordinal_fil(&operands),
+			Id => ordinal_af(&operands),
+			Ig => ordinal_root(&operands),
+			Ii => ordinal_root(&operands),
+			Is => ordinal_af(&operands),
+			It => ordinal_it(&operands),
+			ItCh => ordinal_it(&operands),
+			ItIt => ordinal_it(&operands),
+			Iu => ordinal_root(&operands),
+			Ja => ordinal_af(&operands),
+			Jv => ordinal_root(&operands),
+			Ka => ordinal_ka(&operands),
+			Kg => ordinal_root(&operands),
+			Ki => ordinal_root(&operands),
+			Kk => ordinal_kk(&operands),
+			Kl => ordinal_root(&operands),
+			Km => ordinal_af(&operands),
+			Kn => ordinal_af(&operands),
+			Ko => ordinal_af(&operands),
+			Kok => ordinal_root(&operands),
+			Kr => ordinal_root(&operands),
+			Ks => ordinal_root(&operands),
+			Ky => ordinal_af(&operands),
+			Lb => ordinal_root(&operands),
+			Lg => ordinal_root(&operands),
+			Ln => ordinal_root(&operands),
+			Lo => ordinal_fil(&operands),
+			Lt => ordinal_af(&operands),
+			Lv => ordinal_af(&operands),
+			Mg => ordinal_root(&operands),
+			Mi => ordinal_root(&operands),
+			Mk => ordinal_mk(&operands),
+			Ml => ordinal_af(&operands),
+			Mn => ordinal_af(&operands),
+			MnMn => ordinal_af(&operands),
+			MnMongCn => ordinal_af(&operands),
+			Moh => ordinal_root(&operands),
+			Mr => ordinal_mr(&operands),
+			Ms => ordinal_fil(&operands),
+			MsBn => ordinal_fil(&operands),
+			MsMy => ordinal_fil(&operands),
+			Mt => ordinal_root(&operands),
+			My => ordinal_af(&operands),
+			Nb => ordinal_af(&operands),
+			NbNo => ordinal_af(&operands),
+			Ne => ordinal_ne(&operands),
+			Nl => ordinal_af(&operands),
+			NlBe => ordinal_af(&operands),
+			NlNl => ordinal_af(&operands),
+			Nn => ordinal_root(&operands),
+			No => ordinal_af(&operands),
+			Nso => ordinal_root(&operands),
+			Ny => ordinal_root(&operands),
+			Oc => ordinal_root(&operands),
+			Om => ordinal_root(&operands),
+			Or => ordinal_or(&operands),
+			Pa => ordinal_af(&operands),
+			Pl => ordinal_af(&operands),
+			Prs => ordinal_root(&operands),
+			Ps => ordinal_af(&operands),
+			Pt => ordinal_af(&operands),
+			PtBr => ordinal_af(&operands),
+			PtPt => ordinal_af(&operands),
+			Qu => ordinal_root(&operands),
+			Quc => ordinal_root(&operands),
+			Rm => ordinal_root(&operands),
+			Rn => ordinal_root(&operands),
+			Ro => ordinal_fil(&operands),
+			Ru => ordinal_af(&operands),
+			Rw => ordinal_root(&operands),
+			Sa => ordinal_root(&operands),
+			Sah => ordinal_root(&operands),
+			Sc => ordinal_it(&operands),
+			Sd => ordinal_af(&operands),
+			Se => ordinal_root(&operands),
+			Si => ordinal_af(&operands),
+			Sk => ordinal_af(&operands),
+			Sl => ordinal_af(&operands),
+			Sn => ordinal_root(&operands),
+			So => ordinal_root(&operands),
+			Sq => ordinal_sq(&operands),
+			Sr => ordinal_af(&operands),
+			SrCyrlSp => ordinal_af(&operands),
+			SrLatnSp => ordinal_af(&operands),
+			St => ordinal_root(&operands),
+			Su => ordinal_root(&operands),
+			Sv => ordinal_sv(&operands),
+			SvFi => ordinal_sv(&operands),
+			SvSe => ordinal_sv(&operands),
+			Sw => ordinal_af(&operands),
+			Syr => ordinal_root(&operands),
+			Ta => ordinal_af(&operands),
+			Te => ordinal_af(&operands),
+			Tg => ordinal_root(&operands),
+			Th => ordinal_af(&operands),
+			Ti => ordinal_root(&operands),
+			Tk => ordinal_tk(&operands),
+			Tl => ordinal_fil(&operands),
+			Tn => ordinal_root(&operands),
+			Tr => ordinal_af(&operands),
+			Ts => ordinal_root(&operands),
+			Tt => ordinal_root(&operands),
+			Tzm => ordinal_root(&operands),
+			Ug => ordinal_root(&operands),
+			Uk => ordinal_uk(&operands),
+			Ur => ordinal_af(&operands),
+			Uz => ordinal_af(&operands),
+			UzCyrlUz => ordinal_af(&operands),
+			UzLatnUz => ordinal_af(&operands),
+			Ve => ordinal_root(&operands),
+			Vi => ordinal_fil(&operands),
+			Wo => ordinal_root(&operands),
+			Xh => ordinal_root(&operands),
+			Yo => ordinal_root(&operands),
+			ZhCn => ordinal_af(&operands),
+			ZhHk => ordinal_af(&operands),
+			ZhHans => ordinal_af(&operands),
+			ZhHant => ordinal_af(&operands),
+			ZhMo => ordinal_af(&operands),
+			ZhSg => ordinal_af(&operands),
+			ZhTw => ordinal_af(&operands),
+			Zu => ordinal_af(&operands),
+		}
+	}
+
+	/// Select the CLDR plural category of a range such as "1–3 days" from the categories of its
+	/// start and end values.
+	///
+	/// Pairs without CLDR data take the end category.
+	pub fn plural_range_category(
+		&self,
+		start: PluralCategory,
+		end: PluralCategory,
+	) -> PluralCategory {
+		match self {
+			Af => range_af(start, end),
+			Ak => range_ak(start, end),
+			Am => range_am(start, end),
+			Ar => range_ar(start, end),
+			ArAe => range_ar(start, end),
+			ArBh => range_ar(start, end),
+			ArDz => range_ar(start, end),
+			ArEg => range_ar(start, end),
+			ArIq => range_ar(start, end),
+			ArJo => range_ar(start, end),
+			ArKw => range_ar(start, end),
+			ArLb => range_ar(start, end),
+			ArLy => range_ar(start, end),
+			ArMa => range_ar(start, end),
+			ArOm => range_ar(start, end),
+			ArQa => range_ar(start, end),
+			ArSa => range_ar(start, end),
+			ArSy => range_ar(start, end),
+			ArTn => range_ar(start, end),
+			ArYe => range_ar(start, end),
+			Arn => range_root(start, end),
+			As => range_root(start, end),
+			Az => range_af(start, end),
+			AzCyrlAz => range_af(start, end),
+			AzLatnAz => range_af(start, end),
+			Ba => range_root(start, end),
+			Be => range_be(start, end),
+			Bg => range_af(start, end),
+			Bm => range_root(start, end),
+			Bn => range_am(start, end),
+			BnBd => range_am(start, end),
+			BnIn => range_am(start, end),
+			Bo => range_root(start, end),
+			Br => range_root(start, end),
+			Bs => range_bs(start, end),
+			BsCyrl => range_bs(start, end),
+			BsLatn => range_bs(start, end),
+			Ca => range_ca(start, end),
+			Co => range_root(start, end),
+			Cs => range_cs(start, end),
+			Cy => range_cy(start, end),
+			Da => range_da(start, end),
+			De => range_de(start, end),
+			DeAt => range_de(start, end),
+			DeCh => range_de(start, end),
+			DeDe => range_de(start, end),
+			DeLi => range_de(start, end),
+			DeLu => range_de(start, end),
+			Dsb => range_root(start, end),
+			Dv => range_root(start, end),
+			Ee => range_root(start, end),
+			El => range_af(start, end),
+			En => range_de(start, end),
+			En029 => range_de(start, end),
+			EnAu => range_de(start, end),
+			EnBz => range_de(start, end),
+			EnCa => range_de(start, end),
+			EnGb => range_de(start, end),
+			EnIe => range_de(start, end),
+			EnIn => range_de(start, end),
+			EnJm => range_de(start, end),
+			EnMy => range_de(start, end),
+			EnNz => range_de(start, end),
+			EnPh => range_de(start, end),
+			EnSg => range_de(start, end),
+			EnTt => range_de(start, end),
+			EnUs => range_de(start, end),
+			EnZa => range_de(start, end),
+			EnZw => range_de(start, end),
+			Eo => range_root(start, end),
+			Es => range_ca(start, end),
+			EsAr => range_ca(start, end),
+			EsBo => range_ca(start, end),
+			EsCl => range_ca(start, end),
+			EsCo => range_ca(start, end),
+			EsCr => range_ca(start, end),
+			EsDo => range_ca(start, end),
+			EsEc => range_ca(start, end),
+			EsEs => range_ca(start, end),
+			EsGt => range_ca(start, end),
+			EsHn => range_ca(start, end),
+			EsMx => range_ca(start, end),
+			EsNi => range_ca(start, end),
+			EsPa => range_ca(start, end),
+			EsPe => range_ca(start, end),
+			EsPr => range_ca(start, end),
+			EsPy => range_ca(start, end),
+			EsSv => range_ca(start, end),
+			EsUs => range_ca(start, end),
+			EsUy => range_ca(start, end),
+			EsVe => range_ca(start, end),
+			Et => range_de(start, end),
+			Eu => range_af(start, end),
+			Fa => range_am(start, end),
+			Fi => range_de(start, end),
+			Fil => range_fil(start, end),
+			Fo => range_root(start, end),
+			Fr => range_fr(start, end),
+			FrBe => range_fr(start, end),
+			FrCa => range_fr(start, end),
+			FrCh => range_fr(start, end),
+			FrFr => range_fr(start, end),
+			FrLu => range_fr(start, end),
+			FrMc => range_fr(start, end),
+			Fy => range_de(start, end),
+			Ga => range_ga(start, end),
+			Gd => range_root(start, end),
+			Gl => range_de(start, end),
+			Gn => range_root(start, end),
+			Gsw => range_root(start, end),
+			Gu => range_am(start, end),
+			Ha => range_root(start, end),
+			He => range_he(start, end),
+			Hi => range_am(start, end),
+			Hmn => range_root(start, end),
+			Hr => range_bs(start, end),
+			HrBa => range_bs(start, end),
+			HrHr => range_bs(start, end),
+			Hsb => range_root(start, end),
+			Ht => range_root(start, end),
+			Hu => range_af(start, end),
+			Hy => range_hy(start, end),
+			Id => range_id(start, end),
+			Ig => range_root(start, end),
+			Ii => range_root(start, end),
+			Is => range_is(start, end),
+			It => range_ca(start, end),
+			ItCh => range_ca(start, end),
+			ItIt => range_ca(start, end),
+			Iu => range_root(start, end),
+			Ja => range_id(start, end),
+			Jv => range_root(start, end),
+			Ka => range_ka(start, end),
+			Kg => range_root(start, end),
+			Ki => range_root(start, end),
+			Kk => range_af(start, end),
+			Kl => range_root(start, end),
+			Km => range_id(start, end),
+			Kn => range_am(start, end),
+			Ko => range_id(start, end),
+			Kok => range_root(start, end),
+			Kr => range_root(start, end),
+			Ks => range_root(start, end),
+			Ky => range_af(start, end),
+			Lb => range_root(start, end),
+			Lg => range_root(start, end),
+			Ln => range_ak(start, end),
+			Lo => range_id(start, end),
+			Lt => range_be(start, end),
+			Lv => range_lv(start, end),
+			Mg => range_ak(start, end),
+			Mi => range_root(start, end),
+			Mk => range_mk(start, end),
+			Ml => range_af(start, end),
+			Mn => range_af(start, end),
+			MnMn => range_af(start, end),
+			MnMongCn => range_af(start, end),
+			Moh => range_root(start, end),
+			Mr => range_root(start, end),
+			Ms => range_id(start, end),
+			MsBn => range_id(start, end),
+			MsMy => range_id(start, end),
+			Mt => range_root(start, end),
+			My => range_id(start, end),
+			Nb => range_af(start, end),
+			NbNo => range_af(start, end),
+			Ne => range_af(start, end),
+			Nl => range_de(start, end),
+			NlBe => range_de(start, end),
+			NlNl => range_de(start, end),
+			Nn => range_root(start, end),
+			No => range_af(start, end),
+			Nso => range_root(start, end),
+			Ny => range_root(start, end),
+			Oc => range_root(start, end),
+			Om => range_root(start, end),
+			Or => range_af(start, end),
+			Pa => range_ak(start, end),
+			Pl => range_pl(start, end),
+			Prs => range_root(start, end),
+			Ps => range_af(start, end),
+			Pt => range_pt(start, end),
+			PtBr => range_pt(start, end),
+			PtPt => range_pt(start, end),
+			Qu => range_root(start, end),
+			Quc => range_root(start, end),
+			Rm => range_root(start, end),
+			Rn => range_root(start, end),
+			Ro => range_ro(start, end),
+			Ru => range_be(start, end),
+			Rw => range_root(start, end),
+			Sa => range_root(start, end),
+			Sah => range_root(start, end),
+			Sc => range_de(start, end),
+			Sd => range_af(start, end),
+			Se => range_root(start, end),
+			Si => range_si(start, end),
+			Sk => range_cs(start, end),
+			Sl => range_sl(start, end),
+			Sn => range_root(start, end),
+			So => range_root(start, end),
+			Sq => range_af(start, end),
+			Sr => range_bs(start, end),
+			SrCyrlSp => range_bs(start, end),
+			SrLatnSp => range_bs(start, end),
+			St => range_root(start, end),
+			Su => range_root(start, end),
+			Sv => range_de(start, end),
+			SvFi => range_de(start, end),
+			SvSe => range_de(start, end),
+			Sw => range_de(start, end),
+			Syr => range_root(start, end),
+			Ta => range_af(start, end),
+			Te => range_af(start, end),
+			Tg => range_root(start, end),
+			Th => range_id(start, end),
+			Ti => range_ak(start, end),
+			Tk => range_af(start, end),
+			Tl => range_fil(start, end),
+			Tn => range_root(start, end),
+			Tr => range_af(start, end),
+			Ts => range_root(start, end),
+			Tt => range_root(start, end),
+			Tzm => range_root(start, end),
+			Ug => range_af(start, end),
+			Uk => range_be(start, end),
+			Ur => range_de(start, end),
+			Uz => range_af(start, end),
+			UzCyrlUz => range_af(start, end),
+			UzLatnUz => range_af(start, end),
+			Ve => range_root(start, end),
+			Vi => range_id(start, end),
+			Wo => range_root(start, end),
+			Xh => range_root(start, end),
+			Yo => range_root(start, end),
+			ZhCn => range_id(start, end),
+			ZhHk => range_id(start, end),
+			ZhHans => range_id(start, end),
+			ZhHant => range_id(start, end),
+			ZhMo => range_id(start, end),
+			ZhSg => range_id(start, end),
+			ZhTw => range_id(start, end),
+			Zu => range_am(start, end),
+		}
+	}
+
+	/// Get the Windows locale identifier (LCID).
+	///
+	/// Base languages map to their neutral LCID, such as `0x0009` for `en`. `None` for languages
+	/// Windows only knows as `LOCALE_CUSTOM_UNSPECIFIED`.
+	pub fn lcid(&self) -> Option<u32> {
+		let lcid = match self {
+			Af => 0x0036,
+			Am => 0x005E,
+			Ar => 0x0001,
+			ArAe => 0x3801,
+			ArBh => 0x3C01,
+			ArDz => 0x1401,
+			ArEg => 0x0C01,
+			ArIq => 0x0801,
+			ArJo => 0x2C01,
+			ArKw => 0x3401,
+			ArLb => 0x3001,
+			ArLy => 0x1001,
+			ArMa => 0x1801,
+			ArOm => 0x2001,
+			ArQa => 0x4001,
+			ArSa => 0x0401,
+			ArSy => 0x2801,
+			ArTn => 0x1C01,
+			ArYe => 0x2401,
+			Arn => 0x007A,
+			As => 0x004D,
+			Az => 0x002C,
+			AzCyrlAz => 0x082C,
+			AzLatnAz => 0x042C,
+			Ba => 0x006D,
+			Be => 0x0023,
+			Bg => 0x0002,
+			Bn => 0x0045,
+			BnBd => 0x0845,
+			BnIn => 0x0445,
+			Bo => 0x0051,
+			Br => 0x007E,
+			Bs => 0x781A,
+			BsCyrl => 0x641A,
+			BsLatn => 0x681A,
+			Ca => 0x0003,
+			Co => 0x0083,
+			Cs => 0x0005,
+			Cy => 0x0052,
+			Da => 0x0006,
+			De => 0x0007,
+			DeAt => 0x0C07,
+			DeCh => 0x0807,
+			DeDe => 0x0407,
+			DeLi => 0x1407,
+			DeLu => 0x1007,
+			Dsb => 0x7C2E,
+			Dv => 0x0065,
+			El => 0x0008,
+			En => 0x0009,
+			En029 => 0x2409,
+			EnAu => 0x0C09,
+			EnBz => 0x2809,
+			EnCa => 0x1009,
+			EnGb => 0x0809,
+			EnIe => 0x1809,
+			EnIn => 0x4009,
+			EnJm => 0x2009,
+			EnMy => 0x4409,
+			EnNz => 0x1409,
+			EnPh => 0x3409,
+			EnSg => 0x4809,
+			EnTt => 0x2C09,
+			EnUs => 0x0409,
+			EnZa => 0x1C09,
+			EnZw => 0x3009,
+			Es => 0x000A,
+			EsAr => 0x2C0A,
+			EsBo => 0x400A,
+			EsCl => 0x340A,
+			EsCo => 0x240A,
+			EsCr => 0x140A,
+			EsDo => 0x1C0A,
+			EsEc => 0x300A,
+			EsEs => 0x0C0A,
+			EsGt => 0x100A,
+			EsHn => 0x480A,
+			EsMx => 0x080A,
+			EsNi => 0x4C0A,
+			EsPa => 0x180A,
+			EsPe => 0x280A,
+			EsPr => 0x500A,
+			EsPy => 0x3C0A,
+			EsSv => 0x440A,
+			EsUs => 0x540A,
+			EsUy => 0x380A,
+			EsVe => 0x200A,
+			Et => 0x0025,
+			Eu => 0x002D,
+			Fa => 0x0029,
+			Fi => 0x000B,
+			Fil => 0x0064,
+			Fo => 0x0038,
+			Fr => 0x000C,
+			FrBe => 0x080C,
+			FrCa => 0x0C0C,
+			FrCh => 0x100C,
+			FrFr => 0x040C,
+			FrLu => 0x140C,
+			FrMc => 0x180C,
+			Fy => 0x0062,
+			Ga => 0x003C,
+			Gd => 0x0091,
+			Gl => 0x0056,
+			Gn => 0x0074,
+			Gsw => 0x0084,
+			Gu => 0x0047,
+			Ha => 0x0068,
+			He => 0x000D,
+			Hi => 0x0039,
+			Hr => 0x001A,
+			HrBa => 0x101A,
+			HrHr => 0x041A,
+			Hsb => 0x002E,
+			Hu => 0x000E,
+			Hy => 0x002B,
+			Id => 0x0021,
+			Ig => 0x0070,
+			Ii => 0x0078,
+			Is => 0x000F,
+			It => 0x0010,
+			ItCh => 0x0810,
+			ItIt => 0x0410,
+			Iu => 0x005D,
+			Ja => 0x0011,
+			Ka => 0x0037,
+			Kk => 0x003F,
+			Kl => 0x006F,
+			Km => 0x0053,
+			Kn => 0x004B,
+			Ko => 0x0012,
+			Kok => 0x0057,
+			Kr => 0x0071,
+			Ks => 0x0060,
+			Ky => 0x0040,
+			Lb => 0x006E,
+			Lo => 0x0054,
+			Lt => 0x0027,
+			Lv => 0x0026,
+			Mi => 0x0081,
+			Mk => 0x002F,
+			Ml => 0x004C,
+			Mn => 0x0050,
+			MnMn => 0x0450,
+			MnMongCn => 0x0850,
+			Moh => 0x007C,
+			Mr => 0x004E,
+			Ms => 0x003E,
+			MsBn => 0x083E,
+			MsMy => 0x043E,
+			Mt => 0x003A,
+			My => 0x0055,
+			Nb => 0x7C14,
+			NbNo => 0x0414,
+			Ne => 0x0061,
+			Nl => 0x0013,
+			NlBe => 0x0813,
+			NlNl => 0x0413,
+			Nn => 0x7814,
+			No => 0x0014,
+			Nso => 0x006C,
+			Oc => 0x0082,
+			Om => 0x0072,
+			Or => 0x0048,
+			Pa => 0x0046,
+			Pl => 0x0015,
+			Prs => 0x008C,
+			Ps => 0x0063,
+			Pt => 0x0016,
+			PtBr => 0x0416,
+			PtPt => 0x0816,
+			Quc => 0x0086,
+			Rm => 0x0017,
+			Ro => 0x0018,
+			Ru => 0x0019,
+			Rw => 0x0087,
+			Sa => 0x004F,
+			Sah => 0x0085,
+			Sd => 0x0059,
+			Se => 0x003B,
+			Si => 0x005B,
+			Sk => 0x001B,
+			Sl => 0x0024,
+			So => 0x0077,
+			Sq => 0x001C,
+			Sr => 0x7C1A,
+			SrCyrlSp => 0x0C1A,
+			SrLatnSp => 0x081A,
+			St => 0x0030,
+			Sv => 0x001D,
+			SvFi => 0x081D,
+			SvSe => 0x041D,
+			Sw => 0x0041,
+			Syr => 0x005A,
+			Ta => 0x0049,
+			Te => 0x004A,
+			Tg => 0x0028,
+			Th => 0x001E,
+			Ti => 0x0073,
+			Tk => 0x0042,
+			Tn => 0x0032,
+			Tr => 0x001F,
+			Ts => 0x0031,
+			Tt => 0x0044,
+			Tzm => 0x005F,
+			Ug => 0x0080,
+			Uk => 0x0022,
+			Ur => 0x0020,
+			Uz => 0x0043,
+			UzCyrlUz => 0x0843,
+			UzLatnUz => 0x0443,
+			Ve => 0x0033,
+			Vi => 0x002A,
+			Wo => 0x0088,
+			Xh => 0x0034,
+			Yo => 0x006A,
+			ZhCn => 0x0804,
+			ZhHk => 0x0C04,
+			ZhHans => 0x0004,
+			ZhHant => 0x7C04,
+			ZhMo => 0x1404,
+			ZhSg => 0x1004,
+			ZhTw => 0x0404,
+			Zu => 0x0035,
+			_ => return None,
+		};
+
+		Some(lcid)
+	}
+
+	/// Get the ISO 639-1 code of the base language, such as `de`.
+	pub fn iso639_1(&self) -> Option<&'static str> {
+		match self {
+			Af => Some("af"),
+			Ak => Some("ak"),
+			Am => Some("am"),
+			Ar => Some("ar"),
+			ArAe => Some("ar"),
+			ArBh => Some("ar"),
+			ArDz => Some("ar"),
+			ArEg => Some("ar"),
+			ArIq => Some("ar"),
+			ArJo => Some("ar"),
+			ArKw => Some("ar"),
+			ArLb => Some("ar"),
+			ArLy => Some("ar"),
+			ArMa => Some("ar"),
+			ArOm => Some("ar"),
+			ArQa => Some("ar"),
+			ArSa => Some("ar"),
+			ArSy => Some("ar"),
+			ArTn => Some("ar"),
+			ArYe => Some("ar"),
+			Arn => None,
+			As => Some("as"),
+			Az => Some("az"),
+			AzCyrlAz => Some("az"),
+			AzLatnAz => Some("az"),
+			Ba => Some("ba"),
+			Be => Some("be"),
+			Bg => Some("bg"),
+			Bm => Some("bm"),
+			Bn => Some("bn"),
+			BnBd => Some("bn"),
+			BnIn => Some("bn"),
+			Bo => Some("bo"),
+			Br => Some("br"),
+			Bs => Some("bs"),
+			BsCyrl => Some("bs"),
+			BsLatn => Some("bs"),
+			Ca => Some("ca"),
+			Co => Some("co"),
+			Cs => Some("cs"),
+			Cy => Some("cy"),
+			Da => Some("da"),
+			De => Some("de"),
+			DeAt => Some("de"),
+			DeCh => Some("de"),
+			DeDe => Some("de"),
+			DeLi => Some("de"),
+			DeLu => Some("de"),
+			Dsb => None,
+			Dv => Some("dv"),
+			Ee => Some("ee"),
+			El => Some("el"),
+			En => Some("en"),
+			En029 => Some("en"),
+			EnAu => Some("en"),
+			EnBz => Some("en"),
+			EnCa => Some("en"),
+			EnGb => Some("en"),
+			EnIe => Some("en"),
+			EnIn => Some("en"),
+			EnJm => Some("en"),
+			EnMy => Some("en"),
+			EnNz => Some("en"),
+			EnPh => Some("en"),
+			EnSg => Some("en"),
+			EnTt => Some("en"),
+			EnUs => Some("en"),
+			EnZa => Some("en"),
+			EnZw => Some("en"),
+			Eo => Some("eo"),
+			Es => Some("es"),
+			EsAr => Some("es"),
+			EsBo => Some("es"),
+			EsCl => Some("es"),
+			EsCo => Some("es"),
+			EsCr => Some("es"),
+			EsDo => Some("es"),
+			EsEc => Some("es"),
+			EsEs => Some("es"),
+			EsGt => Some("es"),
+			EsHn => Some("es"),
+			EsMx => Some("es"),
+			EsNi => Some("es"),
+			EsPa => Some("es"),
+			EsPe => Some("es"),
+			EsPr => Some("es"),
+			EsPy => Some("es"),
+			EsSv => Some("es"),
+			EsUs => Some("es"),
+			EsUy => Some("es"),
+			EsVe => Some("es"),
+			Et => Some("et"),
+			Eu => Some("eu"),
+			Fa => Some("fa"),
+			Fi => Some("fi"),
+			Fil => None,
+			Fo => Some("fo"),
+			Fr => Some("fr"),
+			FrBe => Some("fr"),
+			FrCa => Some("fr"),
+			FrCh => Some("fr"),
+			FrFr => Some("fr"),
+			FrLu => Some("fr"),
+			FrMc => Some("fr"),
+			Fy => Some("fy"),
+			Ga => Some("ga"),
+			Gd => Some("gd"),
+			Gl => Some("gl"),
+			Gn => Some("gn"),
+			Gsw => None,
+			Gu => Some("gu"),
+			Ha => Some("ha"),
+			He => Some("he"),
+			Hi => Some("hi"),
+			Hmn => None,
+			Hr => Some("hr"),
+			HrBa => Some("hr"),
+			HrHr => Some("hr"),
+			Hsb => None,
+			Ht => Some("ht"),
+			Hu => Some("hu"),
+			Hy => Some("hy"),
+			Id => Some("id"),
+			Ig => Some("ig"),
+			Ii => Some("ii"),
+			Is => Some("is"),
+			It => Some("it"),
+			ItCh => Some("it"),
+			ItIt => Some("it"),
+			Iu => Some("iu"),
+			Ja => Some("ja"),
+			Jv => Some("jv"),
+			Ka => Some("ka"),
+			Kg => Some("kg"),
+			Ki => Some("ki"),
+			Kk => Some("kk"),
+			Kl => Some("kl"),
+			Km => Some("km"),
+			Kn => Some("kn"),
+			Ko => Some("ko"),
+			Kok => None,
+			Kr => Some("kr"),
+			Ks => Some("ks"),
+			Ky => Some("ky"),
+			Lb => Some("lb"),
+			Lg => Some("lg"),
+			Ln => Some("ln"),
+			Lo => Some("lo"),
+			Lt => Some("lt"),
+			Lv => Some("lv"),
+			Mg => Some("mg"),
+			Mi => Some("mi"),
+			Mk => Some("mk"),
+			Ml => Some("ml"),
+			Mn => Some("mn"),
+			MnMn => Some("mn"),
+			MnMongCn => Some("mn"),
+			Moh => None,
+			Mr => Some("mr"),
+			Ms => Some("ms"),
+			MsBn => Some("ms"),
+			MsMy => Some("ms"),
+			Mt => Some("mt"),
+			My => Some("my"),
+			Nb => Some("nb"),
+			NbNo => Some("nb"),
+			Ne => Some("ne"),
+			Nl => Some("nl"),
+			NlBe => Some("nl"),
+			NlNl => Some("nl"),
+			Nn => Some("nn"),
+			No => Some("no"),
+			Nso => None,
+			Ny => Some("ny"),
+			Oc => Some("oc"),
+			Om => Some("om"),
+			Or => Some("or"),
+			Pa => Some("pa"),
+			Pl => Some("pl"),
+			Prs => None,
+			Ps => Some("ps"),
+			Pt => Some("pt"),
+			PtBr => Some("pt"),
+			PtPt => Some("pt"),
+			Qu => Some("qu"),
+			Quc => None,
+			Rm => Some("rm"),
+			Rn => Some("rn"),
+			Ro => Some("ro"),
+			Ru => Some("ru"),
+			Rw => Some("rw"),
+			Sa => Some("sa"),
+			Sah => None,
+			Sc => Some("sc"),
+			Sd => Some("sd"),
+			Se => Some("se"),
+			Si => Some("si"),
+			Sk => Some("sk"),
+			Sl => Some("sl"),
+			Sn => Some("sn"),
+			So => Some("so"),
+			Sq => Some("sq"),
+			Sr => Some("sr"),
+			SrCyrlSp => Some("sr"),
+			SrLatnSp => Some("sr"),
+			St => Some("st"),
+			Su => Some("su"),
+			Sv => Some("sv"),
+			SvFi => Some("sv"),
+			SvSe => Some("sv"),
+			Sw => Some("sw"),
+			Syr => None,
+			Ta => Some("ta"),
+			Te => Some("te"),
+			Tg => Some("tg"),
+			Th => Some("th"),
+			Ti => Some("ti"),
+			Tk => Some("tk"),
+			Tl => Some("tl"),
+			Tn => Some("tn"),
+			Tr => Some("tr"),
+			Ts => Some("ts"),
+			Tt => Some("tt"),
+			Tzm => None,
+			Ug => Some("ug"),
+			Uk => Some("uk"),
+			Ur => Some("ur"),
+			Uz => Some("uz"),
+			UzCyrlUz => Some("uz"),
+			UzLatnUz => Some("uz"),
+			Ve => Some("ve"),
+			Vi => Some("vi"),
+			Wo => Some("wo"),
+			Xh => Some("xh"),
+			Yo => Some("yo"),
+			ZhCn => Some("zh"),
+			ZhHk => Some("zh"),
+			ZhHans => Some("zh"),
+			ZhHant => Some("zh"),
+			ZhMo => Some("zh"),
+			ZhSg => Some("zh"),
+			ZhTw => Some("zh"),
+			Zu => Some("zu"),
+		}
+	}
+
+	/// Get the ISO 639-2/T (terminology) code of the base language, such as `deu`.
+	pub fn iso639_2t(&self) -> Option<&'static str> {
+		match self {
+			Af => Some("afr"),
+			Ak => Some("aka"),
+			Am => Some("amh"),
+			Ar => Some("ara"),
+			ArAe => Some("ara"),
+			ArBh => Some("ara"),
+			ArDz => Some("ara"),
+			ArEg => Some("ara"),
+			ArIq => Some("ara"),
+			ArJo => Some("ara"),
+			ArKw => Some("ara"),
+			ArLb => Some("ara"),
+			ArLy => Some("ara"),
+			ArMa => Some("ara"),
+			ArOm => Some("ara"),
+			ArQa => Some("ara"),
+			ArSa => Some("ara"),
+			ArSy => Some("ara"),
+			ArTn => Some("ara"),
+			ArYe => Some("ara"),
+			Arn => Some("arn"),
+			As => Some("asm"),
+			Az => Some("aze"),
+			AzCyrlAz => Some("aze"),
+			AzLatnAz => Some("aze"),
+			Ba => Some("bak"),
+			Be => Some("bel"),
+			Bg => Some("bul"),
+			Bm => Some("bam"),
+			Bn => Some("ben"),
+			BnBd => Some("ben"),
+			BnIn => Some("ben"),
+			Bo => Some("bod"),
+			Br => Some("bre"),
+			Bs => Some("bos"),
+			BsCyrl => Some("bos"),
+			BsLatn => Some("bos"),
+			Ca => Some("cat"),
+			Co => Some("cos"),
+			Cs => Some("ces"),
+			Cy => Some("cym"),
+			Da => Some("dan"),
+			De => Some("deu"),
+			DeAt => Some("deu"),
+			DeCh => Some("deu"),
+			DeDe => Some("deu"),
+			DeLi => Some("deu"),
+			DeLu => Some("deu"),
+			Dsb => Some("dsb"),
+			Dv => Some("div"),
+			Ee => Some("ewe"),
+			El => Some("ell"),
+			En => Some("eng"),
+			En029 => Some("eng"),
+			EnAu => Some("eng"),
+			EnBz => Some("eng"),
+			EnCa => Some("eng"),
+			EnGb => Some("eng"),
+			EnIe => Some("eng"),
+			EnIn => Some("eng"),
+			EnJm => Some("eng"),
+			EnMy => Some("eng"),
+			EnNz => Some("eng"),
+			EnPh => Some("eng"),
+			EnSg => Some("eng"),
+			EnTt => Some("eng"),
+			EnUs => Some("eng"),
+			EnZa => Some("eng"),
+			EnZw => Some("eng"),
+			Eo => Some("epo"),
+			Es => Some("spa"),
+			EsAr => Some("spa"),
+			EsBo => Some("spa"),
+			EsCl => Some("spa"),
+			EsCo => Some("spa"),
+			EsCr => Some("spa"),
+			EsDo => Some("spa"),
+			EsEc => Some("spa"),
+			EsEs => Some("spa"),
+			EsGt => Some("spa"),
+			EsHn => Some("spa"),
+			EsMx => Some("spa"),
+			EsNi => Some("spa"),
+			EsPa => Some("spa"),
+			EsPe => Some("spa"),
+			EsPr => Some("spa"),
+			EsPy => Some("spa"),
+			EsSv => Some("spa"),
+			EsUs => Some("spa"),
+			EsUy => Some("spa"),
+			EsVe => Some("spa"),
+			Et => Some("est"),
+			Eu => Some("eus"),
+			Fa => Some("fas"),
+			Fi => Some("fin"),
+			Fil => Some("fil"),
+			Fo => Some("fao"),
+			Fr => Some("fra"),
+			FrBe => Some("fra"),
+			FrCa => Some("fra"),
+			FrCh => Some("fra"),
+			FrFr => Some("fra"),
+			FrLu => Some("fra"),
+			FrMc => Some("fra"),
+			Fy => Some("fry"),
+			Ga => Some("gle"),
+			Gd => Some("gla"),
+			Gl => Some("glg"),
+			Gn => Some("grn"),
+			Gsw => Some("gsw"),
+			Gu => Some("guj"),
+			Ha => Some("hau"),
+			He => Some("heb"),
+			Hi => Some("hin"),
+			Hmn => Some("hmn"),
+			Hr => Some("hrv"),
+			HrBa => Some("hrv"),
+			HrHr => Some("hrv"),
+			Hsb => Some("hsb"),
+			Ht => Some("hat"),
+			Hu => Some("hun"),
+			Hy => Some("hye"),
+			Id => Some("ind"),
+			Ig => Some("ibo"),
+			Ii => Some("iii"),
+			Is => Some("isl"),
+			It => Some("ita"),
+			ItCh => Some("ita"),
+			ItIt => Some("ita"),
+			Iu => Some("iku"),
+			Ja => Some("jpn"),
+			Jv => Some("jav"),
+			Ka => Some("kat"),
+			Kg => Some("kon"),
+			Ki => Some("kik"),
+			Kk => Some("kaz"),
+			Kl => Some("kal"),
+			Km => Some("khm"),
+			Kn => Some("kan"),
+			Ko => Some("kor"),
+			Kok => Some("kok"),
+			Kr => Some("kau"),
+			Ks => Some("kas"),
+			Ky => Some("kir"),
+			Lb => Some("ltz"),
+			Lg => Some("lug"),
+			Ln => Some("lin"),
+			Lo => Some("lao"),
+			Lt => Some("lit"),
+			Lv => Some("lav"),
+			Mg => Some("mlg"),
+			Mi => Some("mri"),
+			Mk => Some("mkd"),
+			Ml => Some("mal"),
+			Mn => Some("mon"),
+			MnMn => Some("mon"),
+			MnMongCn => Some("mon"),
+			Moh => Some("moh"),
+			Mr => Some("mar"),
+			Ms => Some("msa"),
+			MsBn => Some("msa"),
+			MsMy => Some("msa"),
+			Mt => Some("mlt"),
+			My => Some("mya"),
+			Nb => Some("nob"),
+			NbNo => Some("nob"),
+			Ne => Some("nep"),
+			Nl => Some("nld"),
+			NlBe => Some("nld"),
+			NlNl => Some("nld"),
+			Nn => Some("nno"),
+			No => Some("nor"),
+			Nso => Some("nso"),
+			Ny => Some("nya"),
+			Oc => Some("oci"),
+			Om => Some("orm"),
+			Or => Some("ori"),
+			Pa => Some("pan"),
+			Pl => Some("pol"),
+			Prs => None,
+			Ps => Some("pus"),
+			Pt => Some("por"),
+			PtBr => Some("por"),
+			PtPt => Some("por"),
+			Qu => Some("que"),
+			Quc => None,
+			Rm => Some("roh"),
+			Rn => Some("run"),
+			Ro => Some("ron"),
+			Ru => Some("rus"),
+			Rw => Some("kin"),
+			Sa => Some("san"),
+			Sah => Some("sah"),
+			Sc => Some("srd"),
+			Sd => Some("snd"),
+			Se => Some("sme"),
+			Si => Some("sin"),
+			Sk => Some("slk"),
+			Sl => Some("slv"),
+			Sn => Some("sna"),
+			So => Some("som"),
+			Sq => Some("sqi"),
+			Sr => Some("srp"),
+			SrCyrlSp => Some("srp"),
+			SrLatnSp => Some("srp"),
+			St => Some("sot"),
+			Su => Some("sun"),
+			Sv => Some("swe"),
+			SvFi => Some("swe"),
+			SvSe => Some("swe"),
+			Sw => Some("swa"),
+			Syr => Some("syr"),
+			Ta => Some("tam"),
+			Te => Some("tel"),
+			Tg => Some("tgk"),
+			Th => Some("tha"),
+			Ti => Some("tir"),
+			Tk => Some("tuk"),
+			Tl => Some("tgl"),
+			Tn => Some("tsn"),
+			Tr => Some("tur"),
+			Ts => Some("tso"),
+			Tt => Some("tat"),
+			Tzm => None,
+			Ug => Some("uig"),
+			Uk => Some("ukr"),
+			Ur => Some("urd"),
+			Uz => Some("uzb"),
+			UzCyrlUz => Some("uzb"),
+			UzLatnUz => Some("uzb"),
+			Ve => Some("ven"),
+			Vi => Some("vie"),
+			Wo => Some("wol"),
+			Xh => Some("xho"),
+			Yo => Some("yor"),
+			ZhCn => Some("zho"),
+			ZhHk => Some("zho"),
+			ZhHans => Some("zho"),
+			ZhHant => Some("zho"),
+			ZhMo => Some("zho"),
+			ZhSg => Some("zho"),
+			ZhTw => Some("zho"),
+			Zu => Some("zul"),
+		}
+	}
+
+	/// Get the ISO 639-2/B (bibliographic) code of the base language, such as `ger`.
+	pub fn iso639_2b(&self) -> Option<&'static str> {
+		match self {
+			Af => Some("afr"),
+			Ak => Some("aka"),
+			Am => Some("amh"),
+			Ar => Some("ara"),
+			ArAe => Some("ara"),
+			ArBh => Some("ara"),
+			ArDz => Some("ara"),
+			ArEg => Some("ara"),
+			ArIq => Some("ara"),
+			ArJo => Some("ara"),
+			ArKw => Some("ara"),
+			ArLb => Some("ara"),
+			ArLy => Some("ara"),
+			ArMa => Some("ara"),
+			ArOm => Some("ara"),
+			ArQa => Some("ara"),
+			ArSa => Some("ara"),
+			ArSy => Some("ara"),
+			ArTn => Some("ara"),
+			ArYe => Some("ara"),
+			Arn => Some("arn"),
+			As => Some("asm"),
+			Az => Some("aze"),
+			AzCyrlAz => Some("aze"),
+			AzLatnAz => Some("aze"),
+			Ba => Some("bak"),
+			Be => Some("bel"),
+			Bg => Some("bul"),
+			Bm => Some("bam"),
+			Bn => Some("ben"),
+			BnBd => Some("ben"),
+			BnIn => Some("ben"),
+			Bo => Some("tib"),
+			Br => Some("bre"),
+			Bs => Some("bos"),
+			BsCyrl => Some("bos"),
+			BsLatn => Some("bos"),
+			Ca => Some("cat"),
+			Co => Some("cos"),
+			Cs => Some("cze"),
+			Cy => Some("wel"),
+			Da => Some("dan"),
+			De => Some("ger"),
+			DeAt => Some("ger"),
+			DeCh => Some("ger"),
+			DeDe => Some("ger"),
+			DeLi => Some("ger"),
+			DeLu => Some("ger"),
+			Dsb => Some("dsb"),
+			Dv => Some("div"),
+			Ee => Some("ewe"),
+			El => Some("gre"),
+			En => Some("eng"),
+			En029 => Some("eng"),
+			EnAu => Some("eng"),
+			EnBz => Some("eng"),
+			EnCa => Some("eng"),
+			EnGb => Some("eng"),
+			EnIe => Some("eng"),
+			EnIn => Some("eng"),
+			EnJm => Some("eng"),
+			EnMy => Some("eng"),
+			EnNz => Some("eng"),
+			EnPh => Some("eng"),
+			EnSg => Some("eng"),
+			EnTt => Some("eng"),
+			EnUs => Some("eng"),
+			EnZa => Some("eng"),
+			EnZw => Some("eng"),
+			Eo => Some("epo"),
+			Es => Some("spa"),
+			EsAr => Some("spa"),
+			EsBo => Some("spa"),
+			EsCl => Some("spa"),
+			EsCo => Some("spa"),
+			EsCr => Some("spa"),
+			EsDo => Some("spa"),
+			EsEc => Some("spa"),
+			EsEs => Some("spa"),
+			EsGt => Some("spa"),
+			EsHn => Some("spa"),
+			EsMx => Some("spa"),
+			EsNi => Some("spa"),
+			EsPa => Some("spa"),
+			EsPe => Some("spa"),
+			EsPr => Some("spa"),
+			EsPy => Some("spa"),
+			EsSv => Some("spa"),
+			EsUs => Some("spa"),
+			EsUy => Some("spa"),
+			EsVe => Some("spa"),
+			Et => Some("est"),
+			Eu => Some("baq"),
+			Fa => Some("per"),
+			Fi => Some("fin"),
+			Fil => Some("fil"),
+			Fo => Some("fao"),
+			Fr => Some("fre"),
+			FrBe => Some("fre"),
+			FrCa => Some("fre"),
+			FrCh => Some("fre"),
+			FrFr => Some("fre"),
+			FrLu => Some("fre"),
+			FrMc => Some("fre"),
+			Fy => Some("fry"),
+			Ga => Some("gle"),
+			Gd => Some("gla"),
+			Gl => Some("glg"),
+			Gn => Some("grn"),
+			Gsw => Some("gsw"),
+			Gu => Some("guj"),
+			Ha => Some("hau"),
+			He => Some("heb"),
+			Hi => Some("hin"),
+			Hmn => Some("hmn"),
+			Hr => Some("hrv"),
+			HrBa => Some("hrv"),
+			HrHr => Some("hrv"),
+			Hsb => Some("hsb"),
+			Ht => Some("hat"),
+			Hu => Some("hun"),
+			Hy => Some("arm"),
+			Id => Some("ind"),
+			Ig => Some("ibo"),
+			Ii => Some("iii"),
+			Is => Some("ice"),
+			It => Some("ita"),
+			ItCh => Some("ita"),
+			ItIt => Some("ita"),
+			Iu => Some("iku"),
+			Ja => Some("jpn"),
+			Jv => Some("jav"),
+			Ka => Some("geo"),
+			Kg => Some("kon"),
+			Ki => Some("kik"),
+			Kk => Some("kaz"),
+			Kl => Some("kal"),
+			Km => Some("khm"),
+			Kn => Some("kan"),
+			Ko => Some("kor"),
+			Kok => Some("kok"),
+			Kr => Some("kau"),
+			Ks => Some("kas"),
+			Ky => Some("kir"),
+			Lb => Some("ltz"),
+			Lg => Some("lug"),
+			Ln => Some("lin"),
+			Lo => Some("lao"),
+			Lt => Some("lit"),
+			Lv => Some("lav"),
+			Mg => Some("mlg"),
+			Mi => Some("mao"),
+			Mk => Some("mac"),
+			Ml => Some("mal"),
+			Mn => Some("mon"),
+			MnMn => Some("mon"),
+			MnMongCn => Some("mon"),
+			Moh => Some("moh"),
+			Mr => Some("mar"),
+			Ms => Some("may"),
+			MsBn => Some("may"),
+			MsMy => Some("may"),
+			Mt => Some("mlt"),
+			My => Some("bur"),
+			Nb => Some("nob"),
+			NbNo => Some("nob"),
+			Ne => Some("nep"),
+			Nl => Some("dut"),
+			NlBe => Some("dut"),
+			NlNl => Some("dut"),
+			Nn => Some("nno"),
+			No => Some("nor"),
+			Nso => Some("nso"),
+			Ny => Some("nya"),
+			Oc => Some("oci"),
+			Om => Some("orm"),
+			Or => Some("ori"),
+			Pa => Some("pan"),
+			Pl => Some("pol"),
+			Prs => None,
+			Ps => Some("pus"),
+			Pt => Some("por"),
+			PtBr => Some("por"),
+			PtPt => Some("por"),
+			Qu => Some("que"),
+			Quc => None,
+			Rm => Some("roh"),
+			Rn => Some("run"),
+			Ro => Some("rum"),
+			Ru => Some("rus"),
+			Rw => Some("kin"),
+			Sa => Some("san"),
+			Sah => Some("sah"),
+			Sc => Some("srd"),
+			Sd => Some("snd"),
+			Se => Some("sme"),
+			Si => Some("sin"),
+			Sk => Some("slo"),
+			Sl => Some("slv"),
+			Sn => Some("sna"),
+			So => Some("som"),
+			Sq => Some("alb"),
+			Sr => Some("srp"),
+			SrCyrlSp => Some("srp"),
+			SrLatnSp => Some("srp"),
+			St => Some("sot"),
+			Su => Some("sun"),
+			Sv => Some("swe"),
+			SvFi => Some("swe"),
+			SvSe => Some("swe"),
+			Sw => Some("swa"),
+			Syr => Some("syr"),
+			Ta => Some("tam"),
+			Te => Some("tel"),
+			Tg => Some("tgk"),
+			Th => Some("tha"),
+			Ti => Some("tir"),
+			Tk => Some("tuk"),
+			Tl => Some("tgl"),
+			Tn => Some("tsn"),
+			Tr => Some("tur"),
+			Ts => Some("tso"),
+			Tt => Some("tat"),
+			Tzm => None,
+			Ug => Some("uig"),
+			Uk => Some("ukr"),
+			Ur => Some("urd"),
+			Uz => Some("uzb"),
+			UzCyrlUz => Some("uzb"),
+			UzLatnUz => Some("uzb"),
+			Ve => Some("ven"),
+			Vi => Some("vie"),
+			Wo => Some("wol"),
+			Xh => Some("xho"),
+			Yo => Some("yor"),
+			ZhCn => Some("chi"),
+			ZhHk => Some("chi"),
+			ZhHans => Some("chi"),
+			ZhHant => Some("chi"),
+			ZhMo => Some("chi"),
+			ZhSg => Some("chi"),
+			ZhTw => Some("chi"),
+			Zu => Some("zul"),
+		}
+	}
+
+	/// Get the ISO 639-3 code of the base language, such as `deu`.
+	pub fn iso639_3(&self) -> &'static str {
+		match self {
+			Af => "afr",
+			Ak => "aka",
+			Am => "amh",
+			Ar => "ara",
+			ArAe => "ara",
+			ArBh => "ara",
+			ArDz => "ara",
+			ArEg => "ara",
+			ArIq => "ara",
+			ArJo => "ara",
+			ArKw => "ara",
+			ArLb => "ara",
+			ArLy => "ara",
+			ArMa => "ara",
+			ArOm => "ara",
+			ArQa => "ara",
+			ArSa => "ara",
+			ArSy => "ara",
+			ArTn => "ara",
+			ArYe => "ara",
+			Arn => "arn",
+			As => "asm",
+			Az => "aze",
+			AzCyrlAz => "aze",
+			AzLatnAz => "aze",
+			Ba => "bak",
+			Be => "bel",
+			Bg => "bul",
+			Bm => "bam",
+			Bn => "ben",
+			BnBd => "ben",
+			BnIn => "ben",
+			Bo => "bod",
+			Br => "bre",
+			Bs => "bos",
+			BsCyrl => "bos",
+			BsLatn => "bos",
+			Ca => "cat",
+			Co => "cos",
+			Cs => "ces",
+			Cy => "cym",
+			Da => "dan",
+			De => "deu",
+			DeAt => "deu",
+			DeCh => "deu",
+			DeDe => "deu",
+			DeLi => "deu",
+			DeLu => "deu",
+			Dsb => "dsb",
+			Dv => "div",
+			Ee => "ewe",
+			El => "ell",
+			En => "eng",
+			En029 => "eng",
+			EnAu => "eng",
+			EnBz => "eng",
+			EnCa => "eng",
+			EnGb => "eng",
+			EnIe => "eng",
+			EnIn => "eng",
+			EnJm => "eng",
+			EnMy => "eng",
+			EnNz => "eng",
+			EnPh => "eng",
+			EnSg => "eng",
+			EnTt => "eng",
+			EnUs => "eng",
+			EnZa => "eng",
+			EnZw => "eng",
+			Eo => "epo",
+			Es => "spa",
+			EsAr => "spa",
+			EsBo => "spa",
+			EsCl => "spa",
+			EsCo => "spa",
+			EsCr => "spa",
+			EsDo => "spa",
+			EsEc => "spa",
+			EsEs => "spa",
+			EsGt => "spa",
+			EsHn => "spa",
+			EsMx => "spa",
+			EsNi => "spa",
+			EsPa => "spa",
+			EsPe => "spa",
+			EsPr => "spa",
+			EsPy => "spa",
+			EsSv => "spa",
+			EsUs => "spa",
+			EsUy => "spa",
+			EsVe => "spa",
+			Et => "est",
+			Eu => "eus",
+			Fa => "fas",
+			Fi => "fin",
+			Fil => "fil",
+			Fo => "fao",
+			Fr => "fra",
+			FrBe => "fra",
+			FrCa => "fra",
+			FrCh => "fra",
+			FrFr => "fra",
+			FrLu => "fra",
+			FrMc => "fra",
+			Fy => "fry",
+			Ga => "gle",
+			Gd => "gla",
+			Gl => "glg",
+			Gn => "grn",
+			Gsw => "gsw",
+			Gu => "guj",
+			Ha => "hau",
+			He => "heb",
+			Hi => "hin",
+			Hmn => "hmn",
+			Hr => "hrv",
+			HrBa => "hrv",
+			HrHr => "hrv",
+			Hsb => "hsb",
+			Ht => "hat",
+			Hu => "hun",
+			Hy => "hye",
+			Id => "ind",
+			Ig => "ibo",
+			Ii => "iii",
+			Is => "isl",
+			It => "ita",
+			ItCh => "ita",
+			ItIt => "ita",
+			Iu => "iku",
+			Ja => "jpn",
+			Jv => "jav",
+			Ka => "kat",
+			Kg => "kon",
+			Ki => "kik",
+			Kk => "kaz",
+			Kl => "kal",
+			Km => "khm",
+			Kn => "kan",
+			Ko => "kor",
+			Kok => "kok",
+			Kr => "kau",
+			Ks => "kas",
+			Ky => "kir",
+			Lb => "ltz",
+			Lg => "lug",
+			Ln => "lin",
+			Lo => "lao",
+			Lt => "lit",
+			Lv => "lav",
+			Mg => "mlg",
+			Mi => "mri",
+			Mk => "mkd",
+			Ml => "mal",
+			Mn => "mon",
+			MnMn => "mon",
+			MnMongCn => "mon",
+			Moh => "moh",
+			Mr => "mar",
+			Ms => "msa",
+			MsBn => "msa",
+			MsMy => "msa",
+			Mt => "mlt",
+			My => "mya",
+			Nb => "nob",
+			NbNo => "nob",
+			Ne => "nep",
+			Nl => "nld",
+			NlBe => "nld",
+			NlNl => "nld",
+			Nn => "nno",
+			No => "nor",
+			Nso => "nso",
+			Ny => "nya",
+			Oc => "oci",
+			Om => "orm",
+			Or => "ori",
+			Pa => "pan",
+			Pl => "pol",
+			Prs => "prs",
+			Ps => "pus",
+			Pt => "por",
+			PtBr => "por",
+			PtPt => "por",
+			Qu => "que",
+			Quc => "quc",
+			Rm => "roh",
+			Rn => "run",
+			Ro => "ron",
+			Ru => "rus",
+			Rw => "kin",
+			Sa => "san",
+			Sah => "sah",
+			Sc => "srd",
+			Sd => "snd",
+			Se => "sme",
+			Si => "sin",
+			Sk => "slk",
+			Sl => "slv",
+			Sn => "sna",
+			So => "som",
+			Sq => "sqi",
+			Sr => "srp",
+			SrCyrlSp => "srp",
+			SrLatnSp => "srp",
+			St => "sot",
+			Su => "sun",
+			Sv => "swe",
+			SvFi => "swe",
+			SvSe => "swe",
+			Sw => "swa",
+			Syr => "syr",
+			Ta => "tam",
+			Te => "tel",
+			Tg => "tgk",
+			Th => "tha",
+			Ti => "tir",
+			Tk => "tuk",
+			Tl => "tgl",
+			Tn => "tsn",
+			Tr => "tur",
+			Ts => "tso",
+			Tt => "tat",
+			Tzm => "tzm",
+			Ug => "uig",
+			Uk => "ukr",
+			Ur => "urd",
+			Uz => "uzb",
+			UzCyrlUz => "uzb",
+			UzLatnUz => "uzb",
+			Ve => "ven",
+			Vi => "vie",
+			Wo => "wol",
+			Xh => "xho",
+			Yo => "yor",
+			ZhCn => "zho",
+			ZhHk => "zho",
+			ZhHans => "zho",
+			ZhHant => "zho",
+			ZhMo => "zho",
+			ZhSg => "zho",
+			ZhTw => "zho",
+			Zu => "zul",
+		}
+	}
 }
 impl TryFrom<&str> for Language {
 	type Error = Error;
@@ -1790,3 +5467,1418 @@ impl utoipa::PartialSchema for Language {
 }
 #[cfg(feature = "utoipa")]
 impl utoipa::ToSchema for Language {}
+
+#[rustfmt::skip]
+fn cardinal_bm(_: &PluralOperands) -> PluralCategory {
+	Other
+}
+
+#[rustfmt::skip]
+fn cardinal_am(o: &PluralOperands) -> PluralCategory {
+	if o.i == 0 || o.t == 0 && o.i == 1 {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_hy(o: &PluralOperands) -> PluralCategory {
+	if matches!(o.i, 0..=1) {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_de(o: &PluralOperands) -> PluralCategory {
+	if o.i == 1 && o.v == 0 {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_si(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i, 0..=1) || o.i == 0 && o.f == 1 {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_ak(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i, 0..=1) {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_tzm(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i, 0..=1) || o.t == 0 && matches!(o.i, 11..=99) {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_af(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i == 1 {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_da(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i == 1 || o.t != 0 && matches!(o.i, 0..=1) {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_is(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i % 10 == 1 && o.i % 100 != 11 || o.t % 10 == 1 && o.t % 100 != 11 {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_mk(o: &PluralOperands) -> PluralCategory {
+	if o.v == 0 && o.i % 10 == 1 && o.i % 100 != 11 || o.f % 10 == 1 && o.f % 100 != 11 {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_fil(o: &PluralOperands) -> PluralCategory {
+	if o.v == 0 && matches!(o.i, 1..=3) || o.v == 0 && !matches!(o.i % 10, 4 | 6 | 9) || o.v != 0 && !matches!(o.f % 10, 4 | 6 | 9) {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_lv(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i.is_multiple_of(10) || o.t == 0 && matches!(o.i % 100, 11..=19) || o.v == 2 && matches!(o.f % 100, 11..=19) {
+		Zero
+	} else if o.t == 0 && o.i % 10 == 1 && o.i % 100 != 11 || o.v == 2 && o.f % 10 == 1 && o.f % 100 != 11 || o.v != 2 && o.f % 10 == 1 {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_he(o: &PluralOperands) -> PluralCategory {
+	if o.i == 1 && o.v == 0 || o.i == 0 && o.v != 0 {
+		One
+	} else if o.i == 2 && o.v == 0 {
+		Two
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_iu(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i == 1 {
+		One
+	} else if o.t == 0 && o.i == 2 {
+		Two
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_ro(o: &PluralOperands) -> PluralCategory {
+	if o.i == 1 && o.v == 0 {
+		One
+	} else if o.v != 0 || o.t == 0 && o.i == 0 || o.t == 0 && o.i != 1 && matches!(o.i % 100, 1..=19) {
+		Few
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_bs(o: &PluralOperands) -> PluralCategory {
+	if o.v == 0 && o.i % 10 == 1 && o.i % 100 != 11 || o.f % 10 == 1 && o.f % 100 != 11 {
+		One
+	} else if o.v == 0 && matches!(o.i % 10, 2..=4) && !matches!(o.i % 100, 12..=14) || matches!(o.f % 10, 2..=4) && !matches!(o.f % 100, 12..=14) {
+		Few
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_fr(o: &PluralOperands) -> PluralCategory {
+	if matches!(o.i, 0..=1) {
+		One
+	} else if o.c == 0 && o.i != 0 && o.i.is_multiple_of(1_000_000) && o.v == 0 || !matches!(o.c, 0..=5) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_pt(o: &PluralOperands) -> PluralCategory {
+	if matches!(o.i, 0..=1) {
+		One
+	} else if o.c == 0 && o.i != 0 && o.i.is_multiple_of(1_000_000) && o.v == 0 || !matches!(o.c, 0..=5) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_ca(o: &PluralOperands) -> PluralCategory {
+	if o.i == 1 && o.v == 0 {
+		One
+	} else if o.c == 0 && o.i != 0 && o.i.is_multiple_of(1_000_000) && o.v == 0 || !matches!(o.c, 0..=5) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_es(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i == 1 {
+		One
+	} else if o.c == 0 && o.i != 0 && o.i.is_multiple_of(1_000_000) && o.v == 0 || !matches!(o.c, 0..=5) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_gd(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i, 1 | 11) {
+		One
+	} else if o.t == 0 && matches!(o.i, 2 | 12) {
+		Two
+	} else if o.t == 0 && matches!(o.i, 3..=10 | 13..=19) {
+		Few
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_sl(o: &PluralOperands) -> PluralCategory {
+	if o.v == 0 && o.i % 100 == 1 {
+		One
+	} else if o.v == 0 && o.i % 100 == 2 {
+		Two
+	} else if o.v == 0 && matches!(o.i % 100, 3..=4) || o.v != 0 {
+		Few
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_dsb(o: &PluralOperands) -> PluralCategory {
+	if o.v == 0 && o.i % 100 == 1 || o.f % 100 == 1 {
+		One
+	} else if o.v == 0 && o.i % 100 == 2 || o.f % 100 == 2 {
+		Two
+	} else if o.v == 0 && matches!(o.i % 100, 3..=4) || matches!(o.f % 100, 3..=4) {
+		Few
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_cs(o: &PluralOperands) -> PluralCategory {
+	if o.i == 1 && o.v == 0 {
+		One
+	} else if matches!(o.i, 2..=4) && o.v == 0 {
+		Few
+	} else if o.v != 0 {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_pl(o: &PluralOperands) -> PluralCategory {
+	if o.i == 1 && o.v == 0 {
+		One
+	} else if o.v == 0 && matches!(o.i % 10, 2..=4) && !matches!(o.i % 100, 12..=14) {
+		Few
+	} else if o.v == 0 && o.i != 1 && matches!(o.i % 10, 0..=1) || o.v == 0 && matches!(o.i % 10, 5..=9) || o.v == 0 && matches!(o.i % 100, 12..=14) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_be(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i % 10 == 1 && o.i % 100 != 11 {
+		One
+	} else if o.t == 0 && matches!(o.i % 10, 2..=4) && !matches!(o.i % 100, 12..=14) {
+		Few
+	} else if o.t == 0 && o.i.is_multiple_of(10) || o.t == 0 && matches!(o.i % 10, 5..=9) || o.t == 0 && matches!(o.i % 100, 11..=14) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_lt(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i % 10 == 1 && !matches!(o.i % 100, 11..=19) {
+		One
+	} else if o.t == 0 && matches!(o.i % 10, 2..=9) && !matches!(o.i % 100, 11..=19) {
+		Few
+	} else if o.f != 0 {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_ru(o: &PluralOperands) -> PluralCategory {
+	if o.v == 0 && o.i % 10 == 1 && o.i % 100 != 11 {
+		One
+	} else if o.v == 0 && matches!(o.i % 10, 2..=4) && !matches!(o.i % 100, 12..=14) {
+		Few
+	} else if o.v == 0 && o.i.is_multiple_of(10) || o.v == 0 && matches!(o.i % 10, 5..=9) || o.v == 0 && matches!(o.i % 100, 11..=14) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_br(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i % 10 == 1 && !matches!(o.i % 100, 11 | 71 | 91) {
+		One
+	} else if o.t == 0 && o.i % 10 == 2 && !matches!(o.i % 100, 12 | 72 | 92) {
+		Two
+	} else if o.t == 0 && matches!(o.i % 10, 3..=4 | 9) && !matches!(o.i % 100, 10..=19 | 70..=79 | 90..=99) {
+		Few
+	} else if o.t == 0 && o.i != 0 && o.i.is_multiple_of(1_000_000) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_mt(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i == 1 {
+		One
+	} else if o.t == 0 && o.i == 2 {
+		Two
+	} else if o.t == 0 && o.i == 0 || o.t == 0 && matches!(o.i % 100, 3..=10) {
+		Few
+	} else if o.t == 0 && matches!(o.i % 100, 11..=19) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_ga(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i == 1 {
+		One
+	} else if o.t == 0 && o.i == 2 {
+		Two
+	} else if o.t == 0 && matches!(o.i, 3..=6) {
+		Few
+	} else if o.t == 0 && matches!(o.i, 7..=10) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_ar(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i == 0 {
+		Zero
+	} else if o.t == 0 && o.i == 1 {
+		One
+	} else if o.t == 0 && o.i == 2 {
+		Two
+	} else if o.t == 0 && matches!(o.i % 100, 3..=10) {
+		Few
+	} else if o.t == 0 && matches!(o.i % 100, 11..=99) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_cy(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i == 0 {
+		Zero
+	} else if o.t == 0 && o.i == 1 {
+		One
+	} else if o.t == 0 && o.i == 2 {
+		Two
+	} else if o.t == 0 && o.i == 3 {
+		Few
+	} else if o.t == 0 && o.i == 6 {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn cardinal_root(_: &PluralOperands) -> PluralCategory {
+	Other
+}
+
+#[rustfmt::skip]
+fn gettext_bm() -> &'static str {
+	"nplurals=1; plural=0;"
+}
+
+#[rustfmt::skip]
+fn gettext_am() -> &'static str {
+	"nplurals=2; plural=(n==0 || n==1 ? 0 : 1);"
+}
+
+#[rustfmt::skip]
+fn gettext_hy() -> &'static str {
+	"nplurals=2; plural=(n<=1 ? 0 : 1);"
+}
+
+#[rustfmt::skip]
+fn gettext_de() -> &'static str {
+	"nplurals=2; plural=(n==1 ? 0 : 1);"
+}
+
+#[rustfmt::skip]
+fn gettext_si() -> &'static str {
+	"nplurals=2; plural=(n<=1 ? 0 : 1);"
+}
+
+#[rustfmt::skip]
+fn gettext_ak() -> &'static str {
+	"nplurals=2; plural=(n<=1 ? 0 : 1);"
+}
+
+#[rustfmt::skip]
+fn gettext_tzm() -> &'static str {
+	"nplurals=2; plural=(n<=1 || n>=11 && n<=99 ? 0 : 1);"
+}
+
+#[rustfmt::skip]
+fn gettext_af() -> &'static str {
+	"nplurals=2; plural=(n==1 ? 0 : 1);"
+}
+
+#[rustfmt::skip]
+fn gettext_da() -> &'static str {
+	"nplurals=2; plural=(n==1 ? 0 : 1);"
+}
+
+#[rustfmt::skip]
+fn gettext_is() -> &'static str {
+	"nplurals=2; plural=(n%10==1 && n%100!=11 ? 0 : 1);"
+}
+
+#[rustfmt::skip]
+fn gettext_mk() -> &'static str {
+	"nplurals=2; plural=(n%10==1 && n%100!=11 ? 0 : 1);"
+}
+
+#[rustfmt::skip]
+fn gettext_fil() -> &'static str {
+	"nplurals=2; plural=(n>=1 && n<=3 || n%10!=4 && n%10!=6 && n%10!=9 ? 0 : 1);"
+}
+
+#[rustfmt::skip]
+fn gettext_lv() -> &'static str {
+	"nplurals=3; plural=(n%10==0 || n%100>=11 && n%100<=19 ? 0 : n%10==1 && n%100!=11 ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_he() -> &'static str {
+	"nplurals=3; plural=(n==1 ? 0 : n==2 ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_iu() -> &'static str {
+	"nplurals=3; plural=(n==1 ? 0 : n==2 ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_ro() -> &'static str {
+	"nplurals=3; plural=(n==1 ? 0 : n==0 || n!=1 && n%100>=1 && n%100<=19 ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_bs() -> &'static str {
+	"nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<12 || n%100>14) ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_fr() -> &'static str {
+	"nplurals=3; plural=(n<=1 ? 0 : n!=0 && n%1000000==0 ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_pt() -> &'static str {
+	"nplurals=3; plural=(n<=1 ? 0 : n!=0 && n%1000000==0 ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_ca() -> &'static str {
+	"nplurals=3; plural=(n==1 ? 0 : n!=0 && n%1000000==0 ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_es() -> &'static str {
+	"nplurals=3; plural=(n==1 ? 0 : n!=0 && n%1000000==0 ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_gd() -> &'static str {
+	"nplurals=4; plural=((n==1 || n==11) ? 0 : (n==2 || n==12) ? 1 : (n>=3 && n<=10 || n>=13 && n<=19) ? 2 : 3);"
+}
+
+#[rustfmt::skip]
+fn gettext_sl() -> &'static str {
+	"nplurals=4; plural=(n%100==1 ? 0 : n%100==2 ? 1 : n%100>=3 && n%100<=4 ? 2 : 3);"
+}
+
+#[rustfmt::skip]
+fn gettext_dsb() -> &'static str {
+	"nplurals=4; plural=(n%100==1 ? 0 : n%100==2 ? 1 : n%100>=3 && n%100<=4 ? 2 : 3);"
+}
+
+#[rustfmt::skip]
+fn gettext_cs() -> &'static str {
+	"nplurals=3; plural=(n==1 ? 0 : n>=2 && n<=4 ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_pl() -> &'static str {
+	"nplurals=3; plural=(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<12 || n%100>14) ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_be() -> &'static str {
+	"nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<12 || n%100>14) ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_lt() -> &'static str {
+	"nplurals=3; plural=(n%10==1 && (n%100<11 || n%100>19) ? 0 : n%10>=2 && n%10<=9 && (n%100<11 || n%100>19) ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_ru() -> &'static str {
+	"nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<12 || n%100>14) ? 1 : 2);"
+}
+
+#[rustfmt::skip]
+fn gettext_br() -> &'static str {
+	"nplurals=5; plural=(n%10==1 && n%100!=11 && n%100!=71 && n%100!=91 ? 0 : n%10==2 && n%100!=12 && n%100!=72 && n%100!=92 ? 1 : (n%10>=3 && n%10<=4 || n%10==9) && (n%100<10 || n%100>19) && (n%100<70 || n%100>79) && (n%100<90 || n%100>99) ? 2 : n!=0 && n%1000000==0 ? 3 : 4);"
+}
+
+#[rustfmt::skip]
+fn gettext_mt() -> &'static str {
+	"nplurals=5; plural=(n==1 ? 0 : n==2 ? 1 : n==0 || n%100>=3 && n%100<=10 ? 2 : n%100>=11 && n%100<=19 ? 3 : 4);"
+}
+
+#[rustfmt::skip]
+fn gettext_ga() -> &'static str {
+	"nplurals=5; plural=(n==1 ? 0 : n==2 ? 1 : n>=3 && n<=6 ? 2 : n>=7 && n<=10 ? 3 : 4);"
+}
+
+#[rustfmt::skip]
+fn gettext_ar() -> &'static str {
+	"nplurals=6; plural=(n==0 ? 0 : n==1 ? 1 : n==2 ? 2 : n%100>=3 && n%100<=10 ? 3 : n%100>=11 && n%100<=99 ? 4 : 5);"
+}
+
+#[rustfmt::skip]
+fn gettext_cy() -> &'static str {
+	"nplurals=6; plural=(n==0 ? 0 : n==1 ? 1 : n==2 ? 2 : n==3 ? 3 : n==6 ? 4 : 5);"
+}
+
+#[rustfmt::skip]
+fn gettext_root() -> &'static str {
+	"nplurals=1; plural=0;"
+}
+
+#[rustfmt::skip]
+fn samples_bm(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		Other => PluralSamples::new(&["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "100", "1000", "10000", "100000", "1000000"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.1", "1.2", "1.3", "1.4", "1.5", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_am(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["0", "1"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "0.00", "0.01", "0.02", "0.03", "0.04"]),
+		Other => PluralSamples::new(&["2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "100", "1000", "10000", "100000", "1000000"], &["1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "1.7", "1.8", "1.9", "2.0", "2.1", "2.2", "2.3", "2.4", "2.5", "2.6", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_hy(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["0", "1"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.1", "1.2", "1.3", "1.4", "1.5"]),
+		Other => PluralSamples::new(&["2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "100", "1000", "10000", "100000", "1000000"], &["2.0", "2.1", "2.2", "2.3", "2.4", "2.5", "2.6", "2.7", "2.8", "2.9", "3.0", "3.1", "3.2", "3.3", "3.4", "3.5", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_de(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1"], &[]),
+		Other => PluralSamples::new(&["0", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "100", "1000", "10000", "100000", "1000000"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.1", "1.2", "1.3", "1.4", "1.5", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_si(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["0", "1"], &["0.0", "0.1", "1.0", "0.00", "0.01", "1.00", "0.000", "0.001", "1.000", "0.0000", "0.0001", "1.0000"]),
+		Other => PluralSamples::new(&["2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "100", "1000", "10000", "100000", "1000000"], &["0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "1.7", "1.8", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_ak(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["0", "1"], &["0.0", "1.0", "0.00", "1.00", "0.000", "1.000", "0.0000", "1.0000"]),
+		Other => PluralSamples::new(&["2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "100", "1000", "10000", "100000", "1000000"], &["0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "1.7", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_tzm(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["0", "1", "11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "21", "22", "23", "24"], &["0.0", "1.0", "11.0", "12.0", "13.0", "14.0", "15.0", "16.0", "17.0", "18.0", "19.0", "20.0", "21.0", "22.0", "23.0", "24.0"]),
+		Other => PluralSamples::new(&["2", "3", "4", "5", "6", "7", "8", "9", "10", "100", "101", "102", "103", "104", "105", "106", "1000", "10000", "100000", "1000000"], &["0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "1.7", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_af(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1"], &["1.0", "1.00", "1.000", "1.0000"]),
+		Other => PluralSamples::new(&["0", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "100", "1000", "10000", "100000", "1000000"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_da(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1"], &["0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6"]),
+		Other => PluralSamples::new(&["0", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "100", "1000", "10000", "100000", "1000000"], &["0.0", "2.0", "2.1", "2.2", "2.3", "2.4", "2.5", "2.6", "2.7", "2.8", "2.9", "3.0", "3.1", "3.2", "3.3", "3.4", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_is(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1", "21", "31", "41", "51", "61", "71", "81", "101", "1001"], &["0.1", "1.0", "1.1", "2.1", "3.1", "4.1", "5.1", "6.1", "7.1", "10.1", "100.1", "1000.1"]),
+		Other => PluralSamples::new(&["0", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "100", "1000", "10000", "100000", "1000000"], &["0.0", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.2", "1.3", "1.4", "1.5", "1.6", "1.7", "1.8", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_mk(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1", "21", "31", "41", "51", "61", "71", "81", "101", "1001"], &["0.1", "1.1", "2.1", "3.1", "4.1", "5.1", "6.1", "7.1", "10.1", "100.1", "1000.1"]),
+		Other => PluralSamples::new(&["0", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "100", "1000", "10000", "100000", "1000000"], &["0.0", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.2", "1.3", "1.4", "1.5", "1.6", "1.7", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_fil(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["0", "1", "2", "3", "5", "7", "8", "10", "11", "12", "13", "15", "17", "18", "20", "21", "100", "1000", "10000", "100000", "1000000"], &["0.0", "0.1", "0.2", "0.3", "0.5", "0.7", "0.8", "1.0", "1.1", "1.2", "1.3", "1.5", "1.7", "1.8", "2.0", "2.1", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		Other => PluralSamples::new(&["4", "6", "9", "14", "16", "19", "24", "26", "104", "1004"], &["0.4", "0.6", "0.9", "1.4", "1.6", "1.9", "2.4", "2.6", "10.4", "100.4", "1000.4"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_lv(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		Zero => PluralSamples::new(&["0", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "30", "40", "50", "60", "100", "1000", "10000", "100000", "1000000"], &["0.0", "10.0", "11.0", "12.0", "13.0", "14.0", "15.0", "16.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		One => PluralSamples::new(&["1", "21", "31", "41", "51", "61", "71", "81", "101", "1001"], &["0.1", "1.0", "1.1", "2.1", "3.1", "4.1", "5.1", "6.1", "7.1", "10.1", "100.1", "1000.1"]),
+		Other => PluralSamples::new(&["2", "3", "4", "5", "6", "7", "8", "9", "22", "23", "24", "25", "26", "27", "28", "29", "102", "1002"], &["0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.2", "1.3", "1.4", "1.5", "1.6", "1.7", "1.8", "1.9", "10.2", "100.2", "1000.2"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_he(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "0.00", "0.01", "0.02", "0.03", "0.04", "0.05"]),
+		Two => PluralSamples::new(&["2"], &[]),
+		Other => PluralSamples::new(&["0", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "100", "1000", "10000", "100000", "1000000"], &["1.0", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "1.7", "1.8", "1.9", "2.0", "2.1", "2.2", "2.3", "2.4", "2.5", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_iu(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1"], &["1.0", "1.00", "1.000", "1.0000"]),
+		Two => PluralSamples::new(&["2"], &["2.0", "2.00", "2.000", "2.0000"]),
+		Other => PluralSamples::new(&["0", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "100", "1000", "10000", "100000", "1000000"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_ro(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1"], &[]),
+		Few => PluralSamples::new(&["0", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "101", "1001"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.1", "1.2", "1.3", "1.4", "1.5", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		Other => PluralSamples::new(&["20", "21", "22", "23", "24", "25", "26", "27", "28", "29", "30", "31", "32", "33", "34", "35", "100", "1000", "10000", "100000", "1000000"], &[]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_bs(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1", "21", "31", "41", "51", "61", "71", "81", "101", "1001"], &["0.1", "1.1", "2.1", "3.1", "4.1", "5.1", "6.1", "7.1", "10.1", "100.1", "1000.1"]),
+		Few => PluralSamples::new(&["2", "3", "4", "22", "23", "24", "32", "33", "34", "42", "43", "44", "52", "53", "54", "62", "102", "1002"], &["0.2", "0.3", "0.4", "1.2", "1.3", "1.4", "2.2", "2.3", "2.4", "3.2", "3.3", "3.4", "4.2", "4.3", "4.4", "5.2", "10.2", "100.2", "1000.2"]),
+		Other => PluralSamples::new(&["0", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "100", "1000", "10000", "100000", "1000000"], &["0.0", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.5", "1.6", "1.7", "1.8", "1.9", "2.0", "2.5", "2.6", "2.7", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_fr(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["0", "1"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.1", "1.2", "1.3", "1.4", "1.5"]),
+		Many => PluralSamples::new(&["1000000", "1c6", "2c6", "3c6", "4c6", "5c6", "6c6"], &["1.0000001c6", "1.1c6", "2.0000001c6", "2.1c6", "3.0000001c6", "3.1c6"]),
+		Other => PluralSamples::new(&["2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "100", "1000", "10000", "100000", "1c3", "2c3", "3c3", "4c3", "5c3", "6c3"], &["2.0", "2.1", "2.2", "2.3", "2.4", "2.5", "2.6", "2.7", "2.8", "2.9", "3.0", "3.1", "3.2", "3.3", "3.4", "3.5", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0", "1.0001c3", "1.1c3", "2.0001c3", "2.1c3", "3.0001c3", "3.1c3"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_pt(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["0", "1"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.1", "1.2", "1.3", "1.4", "1.5"]),
+		Many => PluralSamples::new(&["1000000", "1c6", "2c6", "3c6", "4c6", "5c6", "6c6"], &["1.0000001c6", "1.1c6", "2.0000001c6", "2.1c6", "3.0000001c6", "3.1c6"]),
+		Other => PluralSamples::new(&["2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "100", "1000", "10000", "100000", "1c3", "2c3", "3c3", "4c3", "5c3", "6c3"], &["2.0", "2.1", "2.2", "2.3", "2.4", "2.5", "2.6", "2.7", "2.8", "2.9", "3.0", "3.1", "3.2", "3.3", "3.4", "3.5", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0", "1.0001c3", "1.1c3", "2.0001c3", "2.1c3", "3.0001c3", "3.1c3"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_ca(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1"], &[]),
+		Many => PluralSamples::new(&["1000000", "1c6", "2c6", "3c6", "4c6", "5c6", "6c6"], &["1.0000001c6", "1.1c6", "2.0000001c6", "2.1c6", "3.0000001c6", "3.1c6"]),
+		Other => PluralSamples::new(&["0", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "100", "1000", "10000", "100000", "1c3", "2c3", "3c3", "4c3", "5c3", "6c3"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.1", "1.2", "1.3", "1.4", "1.5", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0", "1.0001c3", "1.1c3", "2.0001c3", "2.1c3", "3.0001c3", "3.1c3"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_es(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1"], &["1.0", "1.00", "1.000", "1.0000"]),
+		Many => PluralSamples::new(&["1000000", "1c6", "2c6", "3c6", "4c6", "5c6", "6c6"], &["1.0000001c6", "1.1c6", "2.0000001c6", "2.1c6", "3.0000001c6", "3.1c6"]),
+		Other => PluralSamples::new(&["0", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "100", "1000", "10000", "100000", "1c3", "2c3", "3c3", "4c3", "5c3", "6c3"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0", "1.0001c3", "1.1c3", "2.0001c3", "2.1c3", "3.0001c3", "3.1c3"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_gd(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1", "11"], &["1.0", "11.0", "1.00", "11.00", "1.000", "11.000", "1.0000"]),
+		Two => PluralSamples::new(&["2", "12"], &["2.0", "12.0", "2.00", "12.00", "2.000", "12.000", "2.0000"]),
+		Few => PluralSamples::new(&["3", "4", "5", "6", "7", "8", "9", "10", "13", "14", "15", "16", "17", "18", "19"], &["3.0", "4.0", "5.0", "6.0", "7.0", "8.0", "9.0", "10.0", "13.0", "14.0", "15.0", "16.0", "17.0", "18.0", "19.0", "3.00"]),
+		Other => PluralSamples::new(&["0", "20", "21", "22", "23", "24", "25", "26", "27", "28", "29", "30", "31", "32", "33", "34", "100", "1000", "10000", "100000", "1000000"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "10.1", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_sl(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1", "101", "201", "301", "401", "501", "601", "701", "1001"], &[]),
+		Two => PluralSamples::new(&["2", "102", "202", "302", "402", "502", "602", "702", "1002"], &[]),
+		Few => PluralSamples::new(&["3", "4", "103", "104", "203", "204", "303", "304", "403", "404", "503", "504", "603", "604", "703", "704", "1003"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.1", "1.2", "1.3", "1.4", "1.5", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		Other => PluralSamples::new(&["0", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "100", "1000", "10000", "100000", "1000000"], &[]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_dsb(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1", "101", "201", "301", "401", "501", "601", "701", "1001"], &["0.1", "1.1", "2.1", "3.1", "4.1", "5.1", "6.1", "7.1", "10.1", "100.1", "1000.1"]),
+		Two => PluralSamples::new(&["2", "102", "202", "302", "402", "502", "602", "702", "1002"], &["0.2", "1.2", "2.2", "3.2", "4.2", "5.2", "6.2", "7.2", "10.2", "100.2", "1000.2"]),
+		Few => PluralSamples::new(&["3", "4", "103", "104", "203", "204", "303", "304", "403", "404", "503", "504", "603", "604", "703", "704", "1003"], &["0.3", "0.4", "1.3", "1.4", "2.3", "2.4", "3.3", "3.4", "4.3", "4.4", "5.3", "5.4", "6.3", "6.4", "7.3", "7.4", "10.3", "100.3", "1000.3"]),
+		Other => PluralSamples::new(&["0", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "100", "1000", "10000", "100000", "1000000"], &["0.0", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.5", "1.6", "1.7", "1.8", "1.9", "2.0", "2.5", "2.6", "2.7", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_cs(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1"], &[]),
+		Few => PluralSamples::new(&["2", "3", "4"], &[]),
+		Many => PluralSamples::new(&[], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.1", "1.2", "1.3", "1.4", "1.5", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		Other => PluralSamples::new(&["0", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "100", "1000", "10000", "100000", "1000000"], &[]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_pl(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1"], &[]),
+		Few => PluralSamples::new(&["2", "3", "4", "22", "23", "24", "32", "33", "34", "42", "43", "44", "52", "53", "54", "62", "102", "1002"], &[]),
+		Many => PluralSamples::new(&["0", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "100", "1000", "10000", "100000", "1000000"], &[]),
+		Other => PluralSamples::new(&[], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.1", "1.2", "1.3", "1.4", "1.5", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_be(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1", "21", "31", "41", "51", "61", "71", "81", "101", "1001"], &["1.0", "21.0", "31.0", "41.0", "51.0", "61.0", "71.0", "81.0", "101.0", "1001.0"]),
+		Few => PluralSamples::new(&["2", "3", "4", "22", "23", "24", "32", "33", "34", "42", "43", "44", "52", "53", "54", "62", "102", "1002"], &["2.0", "3.0", "4.0", "22.0", "23.0", "24.0", "32.0", "33.0", "102.0", "1002.0"]),
+		Many => PluralSamples::new(&["0", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "100", "1000", "10000", "100000", "1000000"], &["0.0", "5.0", "6.0", "7.0", "8.0", "9.0", "10.0", "11.0", "12.0", "13.0", "14.0", "15.0", "16.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		Other => PluralSamples::new(&[], &["0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "1.7", "10.1", "100.1", "1000.1"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_lt(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1", "21", "31", "41", "51", "61", "71", "81", "101", "1001"], &["1.0", "21.0", "31.0", "41.0", "51.0", "61.0", "71.0", "81.0", "101.0", "1001.0"]),
+		Few => PluralSamples::new(&["2", "3", "4", "5", "6", "7", "8", "9", "22", "23", "24", "25", "26", "27", "28", "29", "102", "1002"], &["2.0", "3.0", "4.0", "5.0", "6.0", "7.0", "8.0", "9.0", "22.0", "102.0", "1002.0"]),
+		Many => PluralSamples::new(&[], &["0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "1.7", "10.1", "100.1", "1000.1"]),
+		Other => PluralSamples::new(&["0", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "30", "40", "50", "60", "100", "1000", "10000", "100000", "1000000"], &["0.0", "10.0", "11.0", "12.0", "13.0", "14.0", "15.0", "16.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_ru(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1", "21", "31", "41", "51", "61", "71", "81", "101", "1001"], &[]),
+		Few => PluralSamples::new(&["2", "3", "4", "22", "23", "24", "32", "33", "34", "42", "43", "44", "52", "53", "54", "62", "102", "1002"], &[]),
+		Many => PluralSamples::new(&["0", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "100", "1000", "10000", "100000", "1000000"], &[]),
+		Other => PluralSamples::new(&[], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.1", "1.2", "1.3", "1.4", "1.5", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_br(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1", "21", "31", "41", "51", "61", "81", "101", "1001"], &["1.0", "21.0", "31.0", "41.0", "51.0", "61.0", "81.0", "101.0", "1001.0"]),
+		Two => PluralSamples::new(&["2", "22", "32", "42", "52", "62", "82", "102", "1002"], &["2.0", "22.0", "32.0", "42.0", "52.0", "62.0", "82.0", "102.0", "1002.0"]),
+		Few => PluralSamples::new(&["3", "4", "9", "23", "24", "29", "33", "34", "39", "43", "44", "49", "103", "1003"], &["3.0", "4.0", "9.0", "23.0", "24.0", "29.0", "33.0", "34.0", "103.0", "1003.0"]),
+		Many => PluralSamples::new(&["1000000"], &["1000000.0", "1000000.00", "1000000.000", "1000000.0000"]),
+		Other => PluralSamples::new(&["0", "5", "6", "7", "8", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "100", "1000", "10000", "100000"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "10.0", "100.0", "1000.0", "10000.0", "100000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_mt(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1"], &["1.0", "1.00", "1.000", "1.0000"]),
+		Two => PluralSamples::new(&["2"], &["2.0", "2.00", "2.000", "2.0000"]),
+		Few => PluralSamples::new(&["0", "3", "4", "5", "6", "7", "8", "9", "10", "103", "104", "105", "106", "107", "108", "109", "1003"], &["0.0", "3.0", "4.0", "5.0", "6.0", "7.0", "8.0", "9.0", "10.0", "103.0", "1003.0"]),
+		Many => PluralSamples::new(&["11", "12", "13", "14", "15", "16", "17", "18", "19", "111", "112", "113", "114", "115", "116", "117", "1011"], &["11.0", "12.0", "13.0", "14.0", "15.0", "16.0", "17.0", "18.0", "111.0", "1011.0"]),
+		Other => PluralSamples::new(&["20", "21", "22", "23", "24", "25", "26", "27", "28", "29", "30", "31", "32", "33", "34", "35", "100", "1000", "10000", "100000", "1000000"], &["0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "1.7", "10.1", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_ga(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		One => PluralSamples::new(&["1"], &["1.0", "1.00", "1.000", "1.0000"]),
+		Two => PluralSamples::new(&["2"], &["2.0", "2.00", "2.000", "2.0000"]),
+		Few => PluralSamples::new(&["3", "4", "5", "6"], &["3.0", "4.0", "5.0", "6.0", "3.00", "4.00", "5.00", "6.00", "3.000", "4.000", "5.000", "6.000", "3.0000", "4.0000", "5.0000", "6.0000"]),
+		Many => PluralSamples::new(&["7", "8", "9", "10"], &["7.0", "8.0", "9.0", "10.0", "7.00", "8.00", "9.00", "10.00", "7.000", "8.000", "9.000", "10.000", "7.0000", "8.0000", "9.0000", "10.0000"]),
+		Other => PluralSamples::new(&["0", "11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "21", "22", "23", "24", "25", "100", "1000", "10000", "100000", "1000000"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "10.1", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_ar(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		Zero => PluralSamples::new(&["0"], &["0.0", "0.00", "0.000", "0.0000"]),
+		One => PluralSamples::new(&["1"], &["1.0", "1.00", "1.000", "1.0000"]),
+		Two => PluralSamples::new(&["2"], &["2.0", "2.00", "2.000", "2.0000"]),
+		Few => PluralSamples::new(&["3", "4", "5", "6", "7", "8", "9", "10", "103", "104", "105", "106", "107", "108", "109", "110", "1003"], &["3.0", "4.0", "5.0", "6.0", "7.0", "8.0", "9.0", "10.0", "103.0", "1003.0"]),
+		Many => PluralSamples::new(&["11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "21", "22", "23", "24", "25", "26", "111", "1011"], &["11.0", "12.0", "13.0", "14.0", "15.0", "16.0", "17.0", "18.0", "111.0", "1011.0"]),
+		Other => PluralSamples::new(&["100", "101", "102", "200", "201", "202", "300", "301", "302", "400", "401", "402", "500", "501", "502", "600", "1000", "10000", "100000", "1000000"], &["0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "1.7", "10.1", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_cy(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		Zero => PluralSamples::new(&["0"], &["0.0", "0.00", "0.000", "0.0000"]),
+		One => PluralSamples::new(&["1"], &["1.0", "1.00", "1.000", "1.0000"]),
+		Two => PluralSamples::new(&["2"], &["2.0", "2.00", "2.000", "2.0000"]),
+		Few => PluralSamples::new(&["3"], &["3.0", "3.00", "3.000", "3.0000"]),
+		Many => PluralSamples::new(&["6"], &["6.0", "6.00", "6.000", "6.0000"]),
+		Other => PluralSamples::new(&["4", "5", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "100", "1000", "10000", "100000", "1000000"], &["0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "1.7", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn samples_root(category: PluralCategory) -> Option<PluralSamples> {
+	let samples = match category {
+		Other => PluralSamples::new(&["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "100", "1000", "10000", "100000", "1000000"], &["0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0", "1.1", "1.2", "1.3", "1.4", "1.5", "10.0", "100.0", "1000.0", "10000.0", "100000.0", "1000000.0"]),
+		_ => return None,
+	};
+
+	Some(samples)
+}
+
+#[rustfmt::skip]
+fn ordinal_af(_: &PluralOperands) -> PluralCategory {
+	Other
+}
+
+#[rustfmt::skip]
+fn ordinal_sv(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i % 10, 1..=2) && !matches!(o.i % 100, 11..=12) {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_fil(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i == 1 {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_hu(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i, 1 | 5) {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_ne(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i, 1..=4) {
+		One
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_be(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i % 10, 2..=3) && !matches!(o.i % 100, 12..=13) {
+		Few
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_uk(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i % 10 == 3 && o.i % 100 != 13 {
+		Few
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_tk(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i % 10, 6 | 9) || o.t == 0 && o.i == 10 {
+		Few
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_kk(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i % 10 == 6 || o.t == 0 && o.i % 10 == 9 || o.t == 0 && o.i.is_multiple_of(10) && o.i != 0 {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_it(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i, 8 | 11 | 80 | 800) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_ka(o: &PluralOperands) -> PluralCategory {
+	if o.i == 1 {
+		One
+	} else if o.i == 0 || matches!(o.i % 100, 2..=20 | 40 | 60 | 80) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_sq(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i == 1 {
+		One
+	} else if o.t == 0 && o.i % 10 == 4 && o.i % 100 != 14 {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_en(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i % 10 == 1 && o.i % 100 != 11 {
+		One
+	} else if o.t == 0 && o.i % 10 == 2 && o.i % 100 != 12 {
+		Two
+	} else if o.t == 0 && o.i % 10 == 3 && o.i % 100 != 13 {
+		Few
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_mr(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i == 1 {
+		One
+	} else if o.t == 0 && matches!(o.i, 2..=3) {
+		Two
+	} else if o.t == 0 && o.i == 4 {
+		Few
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_gd(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i, 1 | 11) {
+		One
+	} else if o.t == 0 && matches!(o.i, 2 | 12) {
+		Two
+	} else if o.t == 0 && matches!(o.i, 3 | 13) {
+		Few
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_ca(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i, 1 | 3) {
+		One
+	} else if o.t == 0 && o.i == 2 {
+		Two
+	} else if o.t == 0 && o.i == 4 {
+		Few
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_mk(o: &PluralOperands) -> PluralCategory {
+	if o.i % 10 == 1 && o.i % 100 != 11 {
+		One
+	} else if o.i % 10 == 2 && o.i % 100 != 12 {
+		Two
+	} else if matches!(o.i % 10, 7..=8) && !matches!(o.i % 100, 17..=18) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_az(o: &PluralOperands) -> PluralCategory {
+	if matches!(o.i % 10, 1..=2 | 5 | 7..=8) || matches!(o.i % 100, 20 | 50 | 70 | 80) {
+		One
+	} else if matches!(o.i % 10, 3..=4) || matches!(o.i % 1_000, 100 | 200 | 300 | 400 | 500 | 600 | 700 | 800 | 900) {
+		Few
+	} else if o.i == 0 || o.i % 10 == 6 || matches!(o.i % 100, 40 | 60 | 90) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_gu(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && o.i == 1 {
+		One
+	} else if o.t == 0 && matches!(o.i, 2..=3) {
+		Two
+	} else if o.t == 0 && o.i == 4 {
+		Few
+	} else if o.t == 0 && o.i == 6 {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_as(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i, 1 | 5 | 7..=10) {
+		One
+	} else if o.t == 0 && matches!(o.i, 2..=3) {
+		Two
+	} else if o.t == 0 && o.i == 4 {
+		Few
+	} else if o.t == 0 && o.i == 6 {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_or(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i, 1 | 5 | 7..=9) {
+		One
+	} else if o.t == 0 && matches!(o.i, 2..=3) {
+		Two
+	} else if o.t == 0 && o.i == 4 {
+		Few
+	} else if o.t == 0 && o.i == 6 {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_cy(o: &PluralOperands) -> PluralCategory {
+	if o.t == 0 && matches!(o.i, 0 | 7..=9) {
+		Zero
+	} else if o.t == 0 && o.i == 1 {
+		One
+	} else if o.t == 0 && o.i == 2 {
+		Two
+	} else if o.t == 0 && matches!(o.i, 3..=4) {
+		Few
+	} else if o.t == 0 && matches!(o.i, 5..=6) {
+		Many
+	} else {
+		Other
+	}
+}
+
+#[rustfmt::skip]
+fn ordinal_root(_: &PluralOperands) -> PluralCategory {
+	Other
+}
+
+#[rustfmt::skip]
+fn range_id(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_am(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_hy(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_de(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_si(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+	match (start, end) {
+		(Other, One) => Other,
+		(_, end) => end,
+	}
+}
+
+#[rustfmt::skip]
+fn range_ak(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_af(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_ka(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+	match (start, end) {
+		(One, Other) => One,
+		(Other, One) => Other,
+		(_, end) => end,
+	}
+}
+
+#[rustfmt::skip]
+fn range_da(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+	match (start, end) {
+		(Other, One) => Other,
+		(_, end) => end,
+	}
+}
+
+#[rustfmt::skip]
+fn range_is(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_mk(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+	match (start, end) {
+		(One, One) => Other,
+		(Other, One) => Other,
+		(_, end) => end,
+	}
+}
+
+#[rustfmt::skip]
+fn range_fil(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_lv(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+	match (start, end) {
+		(Zero, Zero) => Other,
+		(One, Zero) => Other,
+		(Other, Zero) => Other,
+		(_, end) => end,
+	}
+}
+
+#[rustfmt::skip]
+fn range_he(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+	match (start, end) {
+		(One, Two) => Other,
+		(_, end) => end,
+	}
+}
+
+#[rustfmt::skip]
+fn range_ro(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+	match (start, end) {
+		(Few, One) => Few,
+		(_, end) => end,
+	}
+}
+
+#[rustfmt::skip]
+fn range_bs(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_fr(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_pt(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_ca(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_sl(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+	match (start, end) {
+		(One, One) => Few,
+		(Two, One) => Few,
+		(Few, One) => Few,
+		(Other, One) => Few,
+		(_, end) => end,
+	}
+}
+
+#[rustfmt::skip]
+fn range_cs(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_pl(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_be(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_ga(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_ar(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+	match (start, end) {
+		(Zero, One) => Zero,
+		(Zero, Two) => Zero,
+		(One, Two) => Other,
+		(Other, One) => Other,
+		(Other, Two) => Other,
+		(_, end) => end,
+	}
+}
+
+#[rustfmt::skip]
+fn range_cy(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
+
+#[rustfmt::skip]
+fn range_root(_: PluralCategory, end: PluralCategory) -> PluralCategory {
+	end
+}
@@ -0,0 +1,345 @@
+//! gettext `Plural-Forms` headers.
+
+// std
+use std::str::FromStr;
+// self
+use crate::prelude::*;
+
+// Deepest expression tree accepted, far beyond the CLDR-derived headers but shallow enough that
+// parsing, evaluating and dropping never exhaust the stack.
+const MAX_DEPTH: usize = 256;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token {
+	N,
+	Number(u64),
+	Operator(&'static str),
+	LeftParen,
+	RightParen,
+	Question,
+	Colon,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BinaryOperator {
+	Or,
+	And,
+	Eq,
+	Ne,
+	Lt,
+	Le,
+	Gt,
+	Ge,
+	Add,
+	Sub,
+	Mul,
+	Div,
+	Rem,
+}
+impl BinaryOperator {
+	fn parse(operator: &str) -> Option<(Self, u8)> {
+		let this = match operator {
+			"||" => (Self::Or, 1),
+			"&&" => (Self::And, 2),
+			"==" => (Self::Eq, 3),
+			"!=" => (Self::Ne, 3),
+			"<" => (Self::Lt, 4),
+			"<=" => (Self::Le, 4),
+			">" => (Self::Gt, 4),
+			">=" => (Self::Ge, 4),
+			"+" => (Self::Add, 5),
+			"-" => (Self::Sub, 5),
+			"*" => (Self::Mul, 6),
+			"/" => (Self::Div, 6),
+			"%" => (Self::Rem, 6),
+			_ => return None,
+		};
+
+		Some(this)
+	}
+
+	fn apply(self, lhs: u64, rhs: u64) -> Option<u64> {
+		let value = match self {
+			Self::Or => (lhs != 0 || rhs != 0).into(),
+			Self::And => (lhs != 0 && rhs != 0).into(),
+			Self::Eq => (lhs == rhs).into(),
+			Self::Ne => (lhs != rhs).into(),
+			Self::Lt => (lhs < rhs).into(),
+			Self::Le => (lhs <= rhs).into(),
+			Self::Gt => (lhs > rhs).into(),
+			Self::Ge => (lhs >= rhs).into(),
+			Self::Add => lhs.wrapping_add(rhs),
+			Self::Sub => lhs.wrapping_sub(rhs),
+			Self::Mul => lhs.wrapping_mul(rhs),
+			Self::Div => lhs.checked_div(rhs)?,
+			Self::Rem => lhs.checked_rem(rhs)?,
+		};
+
+		Some(value)
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Expression {
+	N,
+	Number(u64),
+	Not(Box<Expression>),
+	Binary(BinaryOperator, Box<Expression>, Box<Expression>),
+	Conditional(Box<Expression>, Box<Expression>, Box<Expression>),
+}
+impl Expression {
+	fn evaluate(&self, n: u64) -> Option<u64> {
+		let value = match self {
+			Self::N => n,
+			Self::Number(value) => *value,
+			Self::Not(operand) => (operand.evaluate(n)? == 0).into(),
+			// `&&` and `||` short-circuit like C, so `n != 0 && 10 / n` stays defined at zero.
+			Self::Binary(BinaryOperator::And, lhs, rhs) =>
+				(lhs.evaluate(n)? != 0 && rhs.evaluate(n)? != 0).into(),
+			Self::Binary(BinaryOperator::Or, lhs, rhs) =>
+				(lhs.evaluate(n)? != 0 || rhs.evaluate(n)? != 0).into(),
+			Self::Binary(operator, lhs, rhs) =>
+				operator.apply(lhs.evaluate(n)?, rhs.evaluate(n)?)?,
+			Self::Conditional(condition, then, otherwise) =>
+				if condition.evaluate(n)? != 0 {
+					then.evaluate(n)?
+				} else {
+					otherwise.evaluate(n)?
+				},
+		};
+
+		Some(value)
+	}
+}
+
+/// Parsed gettext `Plural-Forms` header.
+///
+/// Accepts the header value (`nplurals=2; plural=(n != 1);`), optionally prefixed with
+/// `Plural-Forms:`. The `plural` expression supports the C subset used by gettext: `n`, unsigned
+/// literals, parentheses, `!`, arithmetic, comparisons, `&&`, `||` and `?:`; pathologically deep
+/// expressions are rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PluralForms {
+	nplurals: usize,
+	plural: Expression,
+}
+impl PluralForms {
+	/// Number of plural forms declared by `nplurals`.
+	pub fn nplurals(&self) -> usize {
+		self.nplurals
+	}
+
+	/// Evaluate the `plural` expression and get the form index for `n`.
+	///
+	/// Fails when the expression divides by zero or selects an index outside `nplurals`.
+	pub fn index(&self, n: u64) -> Result<usize> {
+		self.plural
+			.evaluate(n)
+			.and_then(|index| usize::try_from(index).ok())
+			.filter(|index| *index < self.nplurals)
+			.ok_or(Error::PluralFormsEvaluation(n))
+	}
+
+	/// Check that the header selects the same forms as the CLDR cardinal rules of `language`.
+	///
+	/// Form indices are expected to follow [`Language::plural_categories`], keeping only the
+	/// categories with integer samples, since gettext never sees decimals: Russian has the three
+	/// forms `one`, `few` and `many`. Every integer in `0..=1000` and the powers of ten up to
+	/// `10^9` are compared.
+	pub fn agrees_with(&self, language: Language) -> bool {
+		let categories = language
+			.plural_categories()
+			.iter()
+			.copied()
+			.filter(|category| {
+				language
+					.plural_samples(*category)
+					.is_some_and(|samples| !samples.integers().is_empty())
+			})
+			.collect::<Vec<_>>();
+
+		self.nplurals == categories.len()
+			&& (0..=1_000).chain((4..=9).map(|exponent| 10_u64.pow(exponent))).all(|n| {
+				let category = language.plural_category(n);
+
+				self.index(n).ok() == categories.iter().position(|c| *c == category)
+			})
+	}
+}
+impl FromStr for PluralForms {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let invalid = || Error::InvalidPluralForms(s.into());
+		let header = s.trim();
+		let header = match header.split_once(':') {
+			Some((name, value)) if name.trim().eq_ignore_ascii_case("Plural-Forms") => value,
+			_ => header,
+		};
+		let mut nplurals = None;
+		let mut plural = None;
+
+		for field in header.split(';').map(str::trim).filter(|field| !field.is_empty()) {
+			let (name, value) = field.split_once('=').ok_or_else(invalid)?;
+
+			match name.trim() {
+				"nplurals" if nplurals.is_none() =>
+					nplurals = Some(value.trim().parse::<usize>().map_err(|_| invalid())?),
+				"plural" if plural.is_none() =>
+					plural = Some(parse_expression(value).ok_or_else(invalid)?),
+				_ => return Err(invalid()),
+			}
+		}
+
+		match (nplurals, plural) {
+			(Some(nplurals), Some(plural)) if nplurals > 0 => Ok(Self { nplurals, plural }),
+			_ => Err(invalid()),
+		}
+	}
+}
+impl TryFrom<&str> for PluralForms {
+	type Error = Error;
+
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		value.parse()
+	}
+}
+
+fn tokenize(expression: &str) -> Option<Vec<Token>> {
+	let bytes = expression.as_bytes();
+	let mut tokens = Vec::new();
+	let mut i = 0;
+
+	while let Some(&byte) = bytes.get(i) {
+		let token = match byte {
+			b' ' | b'\t' | b'\r' | b'\n' => {
+				i += 1;
+
+				continue;
+			},
+			b'0'..=b'9' => {
+				let start = i;
+
+				while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+					i += 1;
+				}
+
+				tokens.push(Token::Number(expression[start..i].parse().ok()?));
+
+				continue;
+			},
+			b'n' => Token::N,
+			b'(' => Token::LeftParen,
+			b')' => Token::RightParen,
+			b'?' => Token::Question,
+			b':' => Token::Colon,
+			_ => {
+				let operator =
+					["||", "&&", "==", "!=", "<=", ">=", "<", ">", "+", "-", "*", "/", "%", "!"]
+						.into_iter()
+						.find(|operator| expression[i..].starts_with(operator))?;
+
+				i += operator.len();
+				tokens.push(Token::Operator(operator));
+
+				continue;
+			},
+		};
+
+		i += 1;
+		tokens.push(token);
+	}
+
+	Some(tokens)
+}
+
+fn parse_expression(expression: &str) -> Option<Expression> {
+	let tokens = tokenize(expression)?;
+	let mut position = 0;
+	let parsed = parse_conditional(&tokens, &mut position, 0)?;
+
+	(position == tokens.len()).then_some(parsed)
+}
+
+// `depth` counts the enclosing nodes, bounding both the parser recursion and the tree depth.
+fn parse_conditional(tokens: &[Token], position: &mut usize, depth: usize) -> Option<Expression> {
+	if depth > MAX_DEPTH {
+		return None;
+	}
+
+	let condition = parse_binary(tokens, position, 1, depth + 1)?;
+
+	if tokens.get(*position) != Some(&Token::Question) {
+		return Some(condition);
+	}
+
+	*position += 1;
+
+	let then = parse_conditional(tokens, position, depth + 1)?;
+
+	if tokens.get(*position) != Some(&Token::Colon) {
+		return None;
+	}
+
+	*position += 1;
+
+	let otherwise = parse_conditional(tokens, position, depth + 1)?;
+
+	Some(Expression::Conditional(condition.into(), then.into(), otherwise.into()))
+}
+
+fn parse_binary(
+	tokens: &[Token],
+	position: &mut usize,
+	min_precedence: u8,
+	mut depth: usize,
+) -> Option<Expression> {
+	let mut lhs = parse_unary(tokens, position, depth + 1)?;
+
+	// Precedence climbing; every binary operator in the gettext grammar is left-associative.
+	while let Some(Token::Operator(operator)) = tokens.get(*position)
+		&& let Some((operator, precedence)) = BinaryOperator::parse(operator)
+		&& precedence >= min_precedence
+	{
+		*position += 1;
+		// Each operator adds a level above the left operand.
+		depth += 1;
+
+		if depth > MAX_DEPTH {
+			return None;
+		}
+
+		let rhs = parse_binary(tokens, position, precedence + 1, depth + 1)?;
+
+		lhs = Expression::Binary(operator, lhs.into(), rhs.into());
+	}
+
+	Some(lhs)
+}
+
+fn parse_unary(tokens: &[Token], position: &mut usize, depth: usize) -> Option<Expression> {
+	if depth > MAX_DEPTH {
+		return None;
+	}
+
+	let token = *tokens.get(*position)?;
+
+	*position += 1;
+
+	match token {
+		Token::N => Some(Expression::N),
+		Token::Number(value) => Some(Expression::Number(value)),
+		Token::Operator("!") =>
+			Some(Expression::Not(parse_unary(tokens, position, depth + 1)?.into())),
+		Token::LeftParen => {
+			let inner = parse_conditional(tokens, position, depth + 1)?;
+
+			(tokens.get(*position) == Some(&Token::RightParen)).then(|| {
+				*position += 1;
+
+				inner
+			})
+		},
+		_ => None,
+	}
+}
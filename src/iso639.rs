@@ -0,0 +1,43 @@
+// self
+use crate::prelude::*;
+
+impl Language {
+	/// Get the language of an ISO 639-1 code such as `de`.
+	///
+	/// Like the other ISO 639 constructors, the code is case-insensitive and resolves to the base
+	/// language variant, or to [`Language::closest`] of the base tag when the dataset only has
+	/// regional or script variants (`zh` gives [`Language::ZhHans`]).
+	pub fn from_iso639_1(code: &str) -> Result<Self> {
+		Self::from_iso639(code, Self::iso639_1)
+	}
+
+	/// Get the language of an ISO 639-2/T code such as `deu`.
+	pub fn from_iso639_2t(code: &str) -> Result<Self> {
+		Self::from_iso639(code, Self::iso639_2t)
+	}
+
+	/// Get the language of an ISO 639-2/B code such as `ger`, as used by MARC records.
+	pub fn from_iso639_2b(code: &str) -> Result<Self> {
+		Self::from_iso639(code, Self::iso639_2b)
+	}
+
+	/// Get the language of an ISO 639-3 code such as `deu`.
+	pub fn from_iso639_3(code: &str) -> Result<Self> {
+		Self::from_iso639(code, |language| Some(language.iso639_3()))
+	}
+
+	fn from_iso639<F>(code: &str, accessor: F) -> Result<Self>
+	where
+		F: Fn(&Self) -> Option<&'static str>,
+	{
+		let unsupported = || Error::UnsupportedIsoCode(code.into());
+		let code = code.to_ascii_lowercase();
+		let language = Self::all()
+			.into_iter()
+			.find(|language| accessor(language) == Some(code.as_str()))
+			.ok_or_else(unsupported)?;
+		let base = language.tag().split('-').next().unwrap_or_default();
+
+		Self::closest(base).map(|closest| closest.language()).map_err(|_| unsupported())
+	}
+}
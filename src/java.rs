@@ -0,0 +1,56 @@
+// self
+use crate::{alias, matching, prelude::*};
+
+impl Language {
+	/// Parse a Java `Locale.toString()` identifier such as `zh_TW`, `sr_RS_#Latn` or `sr__#Latn`.
+	///
+	/// Variants and the extensions after the script (`ja_JP_JP_#u-ca-japanese`,
+	/// `sr_RS_#Latn_u-nu-latn`) are ignored and legacy codes (`iw`, `in`, `ji`) are accepted.
+	/// Identifiers without a variant that has the same likely subtags, such as `es_419`, fail with
+	/// [`Error::UnsupportedJavaLocale`].
+	pub fn from_java_locale(locale: &str) -> Result<Self> {
+		let unsupported = || Error::UnsupportedJavaLocale(locale.into());
+		let (base, script) = locale.trim().split_once("_#").unwrap_or((locale.trim(), ""));
+		let is_alpha =
+			|field: &&str| !field.is_empty() && field.bytes().all(|b| b.is_ascii_alphabetic());
+		let script =
+			script.split(['_', '-']).next().filter(|script| script.len() == 4 && is_alpha(script));
+		let mut fields = base.split('_');
+		let language = fields.next().filter(is_alpha).ok_or_else(unsupported)?;
+		let region = fields.next().filter(|region| !region.is_empty());
+		let tag =
+			[Some(language), script, region].into_iter().flatten().collect::<Vec<_>>().join("-");
+
+		matching::equivalent(&tag).ok_or_else(unsupported)
+	}
+
+	/// Format as a Java `Locale.toString()` identifier, such as `zh_TW` or `sr_RS_#Latn`.
+	///
+	/// Deprecated regions are written with their preferred value (`SP` → `RS`).
+	pub fn java_locale(&self) -> String {
+		let mut locale = String::new();
+		let mut script = None;
+
+		for (i, subtag) in self.tag().split('-').enumerate() {
+			match subtag.len() {
+				_ if i == 0 => locale.push_str(subtag),
+				4 => script = Some(subtag),
+				_ => {
+					locale.push('_');
+					locale.push_str(alias::preferred_region(subtag).unwrap_or(subtag));
+				},
+			}
+		}
+
+		if let Some(script) = script {
+			if !locale.contains('_') {
+				locale.push('_');
+			}
+
+			locale.push_str("_#");
+			locale.push_str(script);
+		}
+
+		locale
+	}
+}
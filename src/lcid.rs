@@ -0,0 +1,27 @@
+// self
+use crate::{generated::LCIDS, prelude::*};
+
+impl Language {
+	/// Get the language of a Windows locale identifier (LCID) such as `0x0409`.
+	///
+	/// The sort ID (bits 16 to 19, such as `0x0001_0407` for German phonebook order) is ignored,
+	/// but LCIDs with any reserved bit above it set are unsupported. LCIDs of locales the dataset
+	/// lacks resolve through [`Language::closest`], so `0x0436` (`af-ZA`) gives [`Language::Af`]
+	/// and `0x241A` (`sr-Latn-RS`) gives [`Language::SrLatnSp`].
+	pub fn from_lcid(lcid: u32) -> Result<Self> {
+		let unsupported = || Error::UnsupportedLcid(lcid);
+
+		if lcid > 0xF_FFFF {
+			return Err(unsupported());
+		}
+
+		let i = LCIDS
+			.binary_search_by_key(&(lcid & 0xFFFF), |(lcid, _)| *lcid)
+			.map_err(|_| unsupported())?;
+		let tag = LCIDS[i].1;
+
+		Self::try_from(tag)
+			.or_else(|_| Self::closest(tag).map(|closest| closest.language()))
+			.map_err(|_| unsupported())
+	}
+}
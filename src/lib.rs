@@ -3,19 +3,44 @@
 
 #![deny(clippy::all, missing_docs, unused_crate_dependencies)]
 
+pub mod accept_language;
+pub mod alias;
 pub mod error;
+pub mod gettext;
+pub mod matching;
+pub mod message_format;
+pub mod path;
+pub mod plural;
+pub mod tag;
 pub mod prelude {
 	#![allow(missing_docs)]
 
 	pub use crate::{
+		accept_language::*,
+		alias::TagAlias,
 		error::{Error, Result},
 		generated::*,
+		gettext::*,
+		matching::*,
+		message_format::*,
+		path::*,
+		plural::*,
+		tag::*,
 	};
 }
 
+mod android;
+mod apple;
+mod dotnet;
 mod generated;
 #[cfg(feature = "icu_locale_core")] mod icu_locale_core;
+mod iso639;
+mod java;
+mod lcid;
+mod likely;
 #[cfg(feature = "lingua")] mod lingua;
+mod parse;
+mod posix;
 #[cfg(feature = "sqlx")] mod sqlx;
 #[cfg(feature = "whatlang")] mod whatlang;
 
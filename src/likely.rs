@@ -0,0 +1,125 @@
+// self
+use crate::{alias, generated::LIKELY_SUBTAGS, prelude::*};
+
+impl LanguageTag {
+	/// Add likely subtags with the CLDR algorithm, such as `zh-TW` → `zh-Hant-TW`.
+	///
+	/// Deprecated aliases are replaced first, then the first match of `language-Script-REGION`,
+	/// `language-REGION`, `language-Script`, `language` and `und-Script` fills the missing
+	/// language, script and region; variants, extensions and private use are kept. Tags without a
+	/// match, private-use and grandfathered tags are returned unchanged.
+	pub fn maximize(&self) -> Self {
+		let Some(language) = self.language() else {
+			return self.clone();
+		};
+		let canonical = alias::canonicalize(language, &mut Vec::new());
+		let (language, script) = canonical.split_once('-').unwrap_or((&canonical, ""));
+		let mut tag = Self {
+			language: Some(language.into()),
+			script: self.script.clone().or_else(|| (!script.is_empty()).then(|| script.into())),
+			..self.clone()
+		};
+
+		if let Some(region) = tag.region.as_deref().and_then(alias::preferred_region) {
+			tag.region = Some(region.into());
+		}
+
+		let Some(likely) = likely_subtags(language, tag.script.as_deref(), tag.region.as_deref())
+		else {
+			return self.clone();
+		};
+		// Targets are validated as `language-Script-REGION` by the codegen.
+		let [likely_language, likely_script, likely_region] =
+			likely.split('-').collect::<Vec<_>>()[..]
+		else {
+			return self.clone();
+		};
+
+		if tag.language.as_deref() == Some("und") {
+			tag.language = Some(likely_language.into());
+		}
+
+		tag.script = tag.script.or_else(|| Some(likely_script.into()));
+		tag.region = tag.region.or_else(|| Some(likely_region.into()));
+
+		tag
+	}
+
+	/// Remove likely subtags with the CLDR algorithm, such as `az-Latn-AZ` → `az`.
+	///
+	/// The shortest of `language`, `language-REGION` and `language-Script` that maximizes to the
+	/// same tag wins; variants, extensions and private use are kept.
+	pub fn minimize(&self) -> Self {
+		let max = self.maximize();
+
+		if max.script.is_none() || max.region.is_none() {
+			return max;
+		}
+
+		let base = Self { language: max.language.clone(), ..Default::default() };
+		let trials = [
+			base.clone(),
+			Self { region: max.region.clone(), ..base.clone() },
+			Self { script: max.script.clone(), ..base },
+		];
+
+		let minimal = trials.into_iter().find(|trial| {
+			let trial = trial.maximize();
+
+			(&trial.language, &trial.script, &trial.region)
+				== (&max.language, &max.script, &max.region)
+		});
+
+		match minimal {
+			Some(trial) => Self { script: trial.script, region: trial.region, ..max },
+			None => max,
+		}
+	}
+}
+
+impl Language {
+	/// Likely `language-Script-REGION` of the tag, such as `sr` → `sr-Cyrl-RS`.
+	///
+	/// See [`LanguageTag::maximize`]; every [`Language`] has likely subtags.
+	pub fn maximize(&self) -> LanguageTag {
+		LanguageTag::from(*self).maximize()
+	}
+
+	/// Shortest tag with the same likely subtags, such as `az-Latn-AZ` → `az`.
+	///
+	/// See [`LanguageTag::minimize`].
+	pub fn minimize(&self) -> LanguageTag {
+		LanguageTag::from(*self).minimize()
+	}
+}
+
+fn likely_subtags(
+	language: &str,
+	script: Option<&str>,
+	region: Option<&str>,
+) -> Option<&'static str> {
+	let mut lookups = Vec::with_capacity(5);
+
+	if let (Some(script), Some(region)) = (script, region) {
+		lookups.push(format!("{language}-{script}-{region}"));
+	}
+	if let Some(region) = region {
+		lookups.push(format!("{language}-{region}"));
+	}
+	if let Some(script) = script {
+		lookups.push(format!("{language}-{script}"));
+	}
+
+	lookups.push(language.into());
+
+	if let Some(script) = script {
+		lookups.push(format!("und-{script}"));
+	}
+
+	lookups.iter().find_map(|lookup| {
+		LIKELY_SUBTAGS
+			.binary_search_by_key(&lookup.as_str(), |(from, _)| from)
+			.ok()
+			.map(|i| LIKELY_SUBTAGS[i].1)
+	})
+}
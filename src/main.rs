@@ -46,7 +46,21 @@ fn main() -> Result<(), Box<dyn Error>> {
 	let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
 
 	let languages_html = fetch_languages()?;
-	let code = codegen::generate(&languages_html)?;
+	let plurals_xml = fs::read_to_string(manifest_dir.join("build/cldr/plurals.xml"))?;
+	let ordinals_xml = fs::read_to_string(manifest_dir.join("build/cldr/ordinals.xml"))?;
+	let plural_ranges_xml = fs::read_to_string(manifest_dir.join("build/cldr/pluralRanges.xml"))?;
+	let likely_subtags_xml = fs::read_to_string(manifest_dir.join("build/cldr/likelySubtags.xml"))?;
+	let lcids_txt = fs::read_to_string(manifest_dir.join("build/windows/lcids.txt"))?;
+	let iso639_txt = fs::read_to_string(manifest_dir.join("build/iso/iso639.txt"))?;
+	let code = codegen::generate(&codegen::Sources {
+		languages_html: &languages_html,
+		plurals_xml: &plurals_xml,
+		ordinals_xml: &ordinals_xml,
+		plural_ranges_xml: &plural_ranges_xml,
+		likely_subtags_xml: &likely_subtags_xml,
+		lcids_txt: &lcids_txt,
+		iso639_txt: &iso639_txt,
+	})?;
 	let out_path = out_arg
 		.as_ref()
 		.map(PathBuf::from)
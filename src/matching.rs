@@ -0,0 +1,239 @@
+//! Matching arbitrary language tags to the supported [`Language`] variants.
+//!
+//! Besides [`Language::closest`], [`basic_filter`], [`extended_filter`] and [`lookup`] implement
+//! the RFC 4647 matching schemes (<https://www.rfc-editor.org/rfc/rfc4647#section-3>) over
+//! language ranges.
+
+// self
+use crate::{alias, parse, prelude::*};
+
+/// Step taken by [`Language::closest`] to reach a supported language.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MatchStep {
+	/// Extended language, variant, extension or private-use subtags were dropped.
+	Stripped,
+	/// A deprecated subtag was replaced.
+	Alias(TagAlias),
+	/// The tag matched a variant with the same likely subtags, such as `zh-Hant-TW` → `zh-TW`.
+	LikelySubtags,
+	/// The region was dropped or replaced to reach a more general variant, such as `es-419` → `es`.
+	Fallback,
+}
+
+/// Supported language closest to a requested tag, with the steps that reached it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClosestLanguage {
+	language: Language,
+	steps: Vec<MatchStep>,
+}
+impl ClosestLanguage {
+	/// Matched language.
+	pub fn language(&self) -> Language {
+		self.language
+	}
+
+	/// Steps in the order they were applied; empty for an exact match.
+	pub fn steps(&self) -> &[MatchStep] {
+		&self.steps
+	}
+
+	/// Whether the tag matched without any step.
+	pub fn is_exact(&self) -> bool {
+		self.steps.is_empty()
+	}
+}
+
+impl Language {
+	/// Find the supported language closest to any well-formed tag.
+	///
+	/// Variants, extensions and private use are dropped, deprecated aliases are replaced and the
+	/// tag is matched as is when possible. Otherwise the candidates sharing its likely language and
+	/// script are ranked: same likely subtags, then same requested region, then no region, so
+	/// `zh-Hant-TW` → `zh-TW`, `es-419` → `es` and `pt-AO` → `pt`. Separators and case are handled
+	/// like [`Language::parse_lenient`].
+	pub fn closest(tag: &str) -> Result<ClosestLanguage> {
+		let unsupported = || Error::UnsupportedLanguageTag(tag.into());
+		let requested = parse::normalize_case(tag)
+			.and_then(|normalized| LanguageTag::try_from(normalized.as_str()).ok())
+			.filter(|requested| requested.language.is_some())
+			.ok_or_else(unsupported)?;
+		let base = LanguageTag {
+			language: requested.language.clone(),
+			script: requested.script.clone(),
+			region: requested.region.clone(),
+			..Default::default()
+		};
+		let mut steps = Vec::new();
+
+		if base != requested {
+			steps.push(MatchStep::Stripped);
+		}
+		if let Ok((language, aliases)) = Self::parse_with_aliases(&base.to_string()) {
+			steps.extend(aliases.into_iter().map(MatchStep::Alias));
+
+			return Ok(ClosestLanguage { language, steps });
+		}
+
+		let mut aliases = Vec::new();
+
+		alias::canonicalize(&base.to_string(), &mut aliases);
+		steps.extend(aliases.into_iter().map(MatchStep::Alias));
+
+		let max = base.maximize();
+		let (language, (same_likely, ..)) = Self::all()
+			.into_iter()
+			.filter_map(|language| {
+				let tag = LanguageTag::from(language);
+				let candidate = tag.maximize();
+
+				(candidate.language == max.language && candidate.script == max.script).then(|| {
+					let rank = (
+						candidate == max,
+						base.region.is_some()
+							&& tag.region.is_some()
+							&& candidate.region == max.region,
+						tag.region.is_none(),
+						tag.script.is_none(),
+					);
+
+					(language, rank)
+				})
+			})
+			// `max_by_key` keeps the last maximum; reverse to prefer the dataset order on ties.
+			.rev()
+			.max_by_key(|(_, rank)| *rank)
+			.ok_or_else(unsupported)?;
+
+		steps.push(if same_likely { MatchStep::LikelySubtags } else { MatchStep::Fallback });
+
+		Ok(ClosestLanguage { language, steps })
+	}
+}
+
+/// Languages matching any of the basic language ranges, with RFC 4647 basic filtering.
+///
+/// A range matches a tag that equals it or starts with it followed by `-`, case-insensitively, so
+/// `de-CH` matches `de-CH` but not `de`; `*` matches every tag. Matches are grouped by range in
+/// priority order, then follow the order of `available`, without duplicates. Pass
+/// `&Language::all()` to filter every supported language.
+pub fn basic_filter<'a, I>(ranges: I, available: &[Language]) -> Vec<Language>
+where
+	I: IntoIterator<Item = &'a str>,
+{
+	filter(ranges, available, basic_matches)
+}
+
+/// Languages matching any of the extended language ranges, with RFC 4647 extended filtering.
+///
+/// Subtags are compared case-insensitively and `*` matches any number of subtags, so `de-*-CH`
+/// matches `de-CH` and `de-Latn-CH`. A range subtag may skip tag subtags, as `zh-TW` matches
+/// `zh-Hant-TW`, but never past a single-character subtag. Matches are ordered like
+/// [`basic_filter`].
+pub fn extended_filter<'a, I>(ranges: I, available: &[Language]) -> Vec<Language>
+where
+	I: IntoIterator<Item = &'a str>,
+{
+	filter(ranges, available, |range, tag| {
+		let range = range.trim().split('-').collect::<Vec<_>>();
+		let tag = tag.split('-').collect::<Vec<_>>();
+
+		if range[0] != "*" && !range[0].eq_ignore_ascii_case(tag[0]) {
+			return false;
+		}
+
+		let mut tag = &tag[1..];
+
+		for subtag in range[1..].iter().filter(|subtag| **subtag != "*") {
+			loop {
+				let [next, rest @ ..] = tag else {
+					return false;
+				};
+
+				tag = rest;
+
+				if next.eq_ignore_ascii_case(subtag) {
+					break;
+				}
+				if next.len() == 1 {
+					return false;
+				}
+			}
+		}
+
+		true
+	})
+}
+
+/// Best available language for a priority list of language ranges, with RFC 4647 lookup.
+///
+/// Each range, in order, is compared case-insensitively with the available tags and truncated
+/// from the end until one matches; a single-character subtag left at the end (`x` in
+/// `zh-Hant-CN-x-private1`) is truncated with the subtag after it. The `*` range is skipped and `*`
+/// subtags are removed, so `de-*-CH` looks up `de-CH`. Returns `default` when no range matches.
+pub fn lookup<'a, I>(
+	ranges: I,
+	available: &[Language],
+	default: Option<Language>,
+) -> Option<Language>
+where
+	I: IntoIterator<Item = &'a str>,
+{
+	for range in ranges {
+		let mut subtags =
+			range.trim().split('-').filter(|subtag| *subtag != "*").collect::<Vec<_>>();
+
+		while !subtags.is_empty() {
+			let tag = subtags.join("-");
+
+			if let Some(language) =
+				available.iter().find(|language| language.tag().eq_ignore_ascii_case(&tag))
+			{
+				return Some(*language);
+			}
+
+			subtags.pop();
+
+			if subtags.last().is_some_and(|subtag| subtag.len() == 1) {
+				subtags.pop();
+			}
+		}
+	}
+
+	default
+}
+
+// RFC 4647 basic filtering of one tag by one range.
+pub(crate) fn basic_matches(range: &str, tag: &str) -> bool {
+	let range = range.trim();
+
+	range == "*"
+		|| tag.get(..range.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(range))
+			&& matches!(tag.as_bytes().get(range.len()), None | Some(b'-'))
+}
+
+fn filter<'a, I, F>(ranges: I, available: &[Language], matches: F) -> Vec<Language>
+where
+	I: IntoIterator<Item = &'a str>,
+	F: Fn(&str, &str) -> bool,
+{
+	let mut filtered = Vec::new();
+
+	for range in ranges {
+		for language in available {
+			if !filtered.contains(language) && matches(range, language.tag()) {
+				filtered.push(*language);
+			}
+		}
+	}
+
+	filtered
+}
+
+// Language reached by `Language::closest` without a fallback, i.e. one with the same likely
+// subtags.
+pub(crate) fn equivalent(tag: &str) -> Option<Language> {
+	Language::closest(tag)
+		.ok()
+		.filter(|closest| !closest.steps.contains(&MatchStep::Fallback))
+		.map(|closest| closest.language)
+}
@@ -0,0 +1,403 @@
+//! Minimal ICU MessageFormat with `plural`, `selectordinal` and `select` arguments.
+//!
+//! See <https://unicode-org.github.io/icu/userguide/format_parse/messages/>. Only plain arguments
+//! (`{name}`, `{name, number}`) and the three selector types are supported; number styles, dates
+//! and other argument types are rejected at parse time, as are sub-messages nested more than 64
+//! levels deep.
+
+// self
+use crate::prelude::*;
+
+// Deepest nesting of sub-messages accepted; deeper patterns would exhaust the stack.
+const MAX_DEPTH: usize = 64;
+
+macro_rules! impl_from_integer {
+	($($ty:ty),*) => {
+		$(
+			impl From<$ty> for MessageArgument<'_> {
+				fn from(value: $ty) -> Self {
+					Self::Integer(value as i128)
+				}
+			}
+		)*
+	};
+}
+
+/// Value of a MessageFormat argument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageArgument<'a> {
+	/// Integer, selected with its plural operands and printed as is.
+	Integer(i128),
+	/// Formatted decimal such as `"1.50"`; visible fraction digits take part in plural selection.
+	Decimal(&'a str),
+	/// Text, only valid for plain and `select` arguments.
+	Text(&'a str),
+}
+impl MessageArgument<'_> {
+	fn number(&self, offset: u64, name: &str) -> Result<(String, PluralOperands)> {
+		let invalid = || Error::InvalidMessageArgument(name.into());
+		let number = match self {
+			Self::Integer(value) =>
+				value.checked_sub(i128::from(offset)).ok_or_else(invalid)?.to_string(),
+			Self::Decimal(value) if offset == 0 => {
+				PluralOperands::try_from(*value).map_err(|_| invalid())?;
+
+				value.to_string()
+			},
+			Self::Decimal(value) => {
+				let operands = PluralOperands::try_from(*value).map_err(|_| invalid())?;
+				let value = value.parse::<f64>().map_err(|_| invalid())?;
+
+				format!("{:.*}", operands.v(), value - offset as f64)
+			},
+			Self::Text(_) => return Err(invalid()),
+		};
+		let operands = number.parse().map_err(|_| invalid())?;
+
+		Ok((number, operands))
+	}
+
+	fn equals(&self, exact: &str) -> bool {
+		match self {
+			// `=1.0` matches `1`; integer keys too large for an `f64` are compared exactly.
+			Self::Integer(value) => match exact.parse::<i128>() {
+				Ok(exact) => exact == *value,
+				Err(_) => exact.parse::<f64>().is_ok_and(|exact| {
+					exact.fract() == 0.0 && exact.abs() < 2_f64.powi(53) && exact as i128 == *value
+				}),
+			},
+			Self::Decimal(value) => value
+				.parse::<f64>()
+				.is_ok_and(|value| exact.parse::<f64>().is_ok_and(|exact| exact == value)),
+			Self::Text(_) => false,
+		}
+	}
+}
+impl<'a> From<&'a str> for MessageArgument<'a> {
+	fn from(value: &'a str) -> Self {
+		Self::Text(value)
+	}
+}
+impl_from_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Part {
+	Text(String),
+	Argument(String),
+	// `#` inside a plural sub-message.
+	Number,
+	Plural {
+		name: String,
+		ordinal: bool,
+		offset: u64,
+		exact: Vec<(String, Vec<Part>)>,
+		cases: Vec<(PluralCategory, Vec<Part>)>,
+	},
+	Select {
+		name: String,
+		cases: Vec<(String, Vec<Part>)>,
+	},
+}
+
+/// Parsed MessageFormat pattern bound to a [`Language`] for plural selection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageFormat {
+	language: Language,
+	parts: Vec<Part>,
+}
+impl MessageFormat {
+	/// Parse a pattern.
+	pub fn new(language: Language, pattern: &str) -> Result<Self> {
+		let chars = pattern.chars().collect::<Vec<_>>();
+		let mut parser = Parser { chars: &chars, position: 0, depth: 0 };
+		let parts = parser
+			.message(false)
+			.filter(|_| parser.position == chars.len())
+			.ok_or_else(|| Error::InvalidMessageFormat(pattern.into()))?;
+
+		Ok(Self { language, parts })
+	}
+
+	/// Language used for plural selection.
+	pub fn language(&self) -> Language {
+		self.language
+	}
+
+	/// Format the message with named arguments.
+	pub fn format<'a, I>(&self, arguments: I) -> Result<String>
+	where
+		I: IntoIterator<Item = (&'a str, MessageArgument<'a>)>,
+	{
+		let arguments = arguments.into_iter().collect::<Vec<_>>();
+		let mut out = String::new();
+
+		self.format_parts(&self.parts, &arguments, None, &mut out)?;
+
+		Ok(out)
+	}
+
+	fn format_parts(
+		&self,
+		parts: &[Part],
+		arguments: &[(&str, MessageArgument)],
+		number: Option<&str>,
+		out: &mut String,
+	) -> Result<()> {
+		let argument = |name: &str| {
+			arguments
+				.iter()
+				.find(|(key, _)| *key == name)
+				.map(|(_, value)| *value)
+				.ok_or_else(|| Error::MissingMessageArgument(name.into()))
+		};
+
+		for part in parts {
+			match part {
+				Part::Text(text) => out.push_str(text),
+				Part::Argument(name) => match argument(name)? {
+					MessageArgument::Integer(value) => out.push_str(&value.to_string()),
+					MessageArgument::Decimal(value) | MessageArgument::Text(value) =>
+						out.push_str(value),
+				},
+				Part::Number => out.push_str(number.unwrap_or("#")),
+				Part::Plural { name, ordinal, offset, exact, cases } => {
+					let value = argument(name)?;
+					let (formatted, operands) = value.number(*offset, name)?;
+					// Exact matches compare the value before the offset is applied.
+					let message = match exact.iter().find(|(exact, _)| value.equals(exact)) {
+						Some((_, message)) => message,
+						None => {
+							let category = if *ordinal {
+								self.language.ordinal_category(operands.i())
+							} else {
+								self.language.plural_category_for(&operands)
+							};
+
+							cases
+								.iter()
+								.find(|(case, _)| *case == category)
+								.or_else(|| {
+									cases.iter().find(|(case, _)| *case == PluralCategory::Other)
+								})
+								.map(|(_, message)| message)
+								.ok_or_else(|| Error::InvalidMessageArgument(name.into()))?
+						},
+					};
+
+					self.format_parts(message, arguments, Some(&formatted), out)?;
+				},
+				Part::Select { name, cases } => {
+					let MessageArgument::Text(value) = argument(name)? else {
+						return Err(Error::InvalidMessageArgument(name.into()));
+					};
+					let message = cases
+						.iter()
+						.find(|(case, _)| case == value)
+						.or_else(|| cases.iter().find(|(case, _)| case == "other"))
+						.map(|(_, message)| message)
+						.ok_or_else(|| Error::InvalidMessageArgument(name.into()))?;
+
+					self.format_parts(message, arguments, number, out)?;
+				},
+			}
+		}
+
+		Ok(())
+	}
+}
+
+struct Parser<'a> {
+	chars: &'a [char],
+	position: usize,
+	depth: usize,
+}
+impl Parser<'_> {
+	fn peek(&self) -> Option<char> {
+		self.chars.get(self.position).copied()
+	}
+
+	fn eat(&mut self, expected: char) -> Option<()> {
+		(self.peek()? == expected).then(|| self.position += 1)
+	}
+
+	fn skip_whitespace(&mut self) {
+		while self.peek().is_some_and(char::is_whitespace) {
+			self.position += 1;
+		}
+	}
+
+	fn word(&mut self) -> Option<String> {
+		self.skip_whitespace();
+
+		let start = self.position;
+
+		while self.peek().is_some_and(|c| !c.is_whitespace() && !matches!(c, '{' | '}' | ',')) {
+			self.position += 1;
+		}
+
+		let word = self.chars[start..self.position].iter().collect::<String>();
+
+		self.skip_whitespace();
+
+		(!word.is_empty()).then_some(word)
+	}
+
+	// Parse until the closing `}` of a sub-message, or the end of a top-level pattern.
+	fn message(&mut self, in_plural: bool) -> Option<Vec<Part>> {
+		let mut parts = Vec::new();
+		let mut text = String::new();
+
+		while let Some(c) = self.peek() {
+			match c {
+				'}' => break,
+				'{' => {
+					if !text.is_empty() {
+						parts.push(Part::Text(std::mem::take(&mut text)));
+					}
+
+					self.position += 1;
+					parts.push(self.argument(in_plural)?);
+				},
+				'#' if in_plural => {
+					if !text.is_empty() {
+						parts.push(Part::Text(std::mem::take(&mut text)));
+					}
+
+					self.position += 1;
+					parts.push(Part::Number);
+				},
+				'\'' => {
+					self.position += 1;
+
+					// `''` is an apostrophe; `'` before a syntax character quotes up to the next
+					// `'`; any other apostrophe is literal.
+					match self.peek() {
+						Some('\'') => {
+							self.position += 1;
+							text.push('\'');
+						},
+						Some(next) if matches!(next, '{' | '}') || (next == '#' && in_plural) =>
+							while let Some(quoted) = self.peek() {
+								self.position += 1;
+
+								if quoted != '\'' {
+									text.push(quoted);
+								} else if self.eat('\'').is_some() {
+									text.push('\'');
+								} else {
+									break;
+								}
+							},
+						_ => text.push('\''),
+					}
+				},
+				_ => {
+					self.position += 1;
+					text.push(c);
+				},
+			}
+		}
+
+		if !text.is_empty() {
+			parts.push(Part::Text(text));
+		}
+
+		Some(parts)
+	}
+
+	// Parse after the opening `{` up to and including the closing `}`.
+	fn argument(&mut self, in_plural: bool) -> Option<Part> {
+		let name = self.word()?;
+
+		if self.eat('}').is_some() {
+			return Some(Part::Argument(name));
+		}
+
+		self.eat(',')?;
+
+		let kind = self.word()?;
+		let part = match kind.as_str() {
+			"number" => Part::Argument(name),
+			"plural" | "selectordinal" => {
+				self.eat(',')?;
+
+				let ordinal = kind == "selectordinal";
+				let mut offset = 0;
+				let mut exact = Vec::new();
+				let mut cases = Vec::new();
+
+				while self.peek() != Some('}') {
+					let key = self.word()?;
+
+					if let Some(value) = key.strip_prefix("offset:") {
+						if !exact.is_empty() || !cases.is_empty() {
+							return None;
+						}
+
+						offset = if value.is_empty() { self.word()? } else { value.into() }
+							.parse()
+							.ok()?;
+
+						continue;
+					}
+
+					let message = self.sub_message(true)?;
+
+					match key.strip_prefix('=') {
+						Some(value) => {
+							value.parse::<f64>().ok()?;
+							exact.push((value.into(), message));
+						},
+						None => cases.push((PluralCategory::try_from(key.as_str()).ok()?, message)),
+					}
+				}
+
+				if !cases.iter().any(|(case, _)| *case == PluralCategory::Other) {
+					return None;
+				}
+
+				Part::Plural { name, ordinal, offset, exact, cases }
+			},
+			"select" => {
+				self.eat(',')?;
+
+				let mut cases = Vec::new();
+
+				while self.peek() != Some('}') {
+					let key = self.word()?;
+
+					cases.push((key, self.sub_message(in_plural)?));
+				}
+
+				if !cases.iter().any(|(case, _)| case == "other") {
+					return None;
+				}
+
+				Part::Select { name, cases }
+			},
+			_ => return None,
+		};
+
+		self.eat('}')?;
+
+		Some(part)
+	}
+
+	fn sub_message(&mut self, in_plural: bool) -> Option<Vec<Part>> {
+		self.eat('{')?;
+
+		if self.depth == MAX_DEPTH {
+			return None;
+		}
+
+		self.depth += 1;
+
+		let message = self.message(in_plural)?;
+
+		self.depth -= 1;
+		self.eat('}')?;
+		self.skip_whitespace();
+
+		Some(message)
+	}
+}
@@ -0,0 +1,51 @@
+// std
+use std::str::FromStr;
+// self
+use crate::prelude::*;
+
+impl Language {
+	/// Parse a tag case- and separator-insensitively.
+	///
+	/// Accepts `_` as well as `-` between subtags and fixes the case of each subtag by its type
+	/// (`language`, `Script`, `REGION`), so `en_us`, `EN-us` and `zh-hant` all resolve.
+	/// Deprecated subtags are replaced as in [`Language::parse_with_aliases`]. Use `TryFrom<&str>`
+	/// to require the canonical spelling.
+	pub fn parse_lenient(tag: &str) -> Result<Self> {
+		Self::parse_with_aliases(tag).map(|(language, _)| language)
+	}
+}
+impl FromStr for Language {
+	type Err = Error;
+
+	/// Same as [`Language::parse_lenient`].
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse_lenient(s)
+	}
+}
+
+// Apply the RFC 5646 case conventions and join subtags with `-`.
+pub(crate) fn normalize_case(tag: &str) -> Option<String> {
+	let mut normalized = String::with_capacity(tag.len());
+
+	for (i, subtag) in tag.trim().split(['-', '_']).enumerate() {
+		if subtag.is_empty() || !subtag.bytes().all(|b| b.is_ascii_alphanumeric()) {
+			return None;
+		}
+		if i > 0 {
+			normalized.push('-');
+		}
+
+		let is_alpha = subtag.bytes().all(|b| b.is_ascii_alphabetic());
+
+		match subtag.len() {
+			4 if i > 0 && is_alpha => {
+				normalized.push_str(&subtag[..1].to_ascii_uppercase());
+				normalized.push_str(&subtag[1..].to_ascii_lowercase());
+			},
+			2 if i > 0 && is_alpha => normalized.push_str(&subtag.to_ascii_uppercase()),
+			_ => normalized.push_str(&subtag.to_ascii_lowercase()),
+		}
+	}
+
+	Some(normalized)
+}
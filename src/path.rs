@@ -0,0 +1,147 @@
+//! Languages embedded in file names and paths, such as `messages.fr_CA.json` or `pt-BR/app.ftl`.
+
+// std
+use std::path::{Component, Path};
+// self
+use crate::prelude::*;
+
+/// Separators and ignored segments used by [`Language::from_path_with`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PathOptions {
+	name_separators: Vec<char>,
+	subtag_separators: Vec<char>,
+	ignored_segments: Vec<String>,
+}
+impl PathOptions {
+	/// Characters splitting a file stem into segments; `.` by default.
+	pub fn name_separators(mut self, separators: &[char]) -> Self {
+		self.name_separators = separators.to_vec();
+
+		self
+	}
+
+	/// Characters accepted between subtags of a segment; `-` and `_` by default.
+	pub fn subtag_separators(mut self, separators: &[char]) -> Self {
+		self.subtag_separators = separators.to_vec();
+
+		self
+	}
+
+	/// File stem segments skipped before the language, compared case-insensitively; `forced`,
+	/// `sdh` and `cc` by default, so `movie.en.forced.srt` matches `en`.
+	pub fn ignored_segments(mut self, segments: &[&str]) -> Self {
+		self.ignored_segments = segments.iter().map(|s| s.to_string()).collect();
+
+		self
+	}
+
+	fn language(&self, segment: &str) -> Option<Language> {
+		let tag = segment.replace(self.subtag_separators.as_slice(), "-");
+
+		Language::try_from(tag.as_str()).ok()
+	}
+}
+impl Default for PathOptions {
+	fn default() -> Self {
+		Self {
+			name_separators: vec!['.'],
+			subtag_separators: vec!['-', '_'],
+			ignored_segments: ["forced", "sdh", "cc"].into_iter().map(Into::into).collect(),
+		}
+	}
+}
+
+/// Language found in a path, with the file stem left after removing it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PathLanguage {
+	language: Language,
+	stem: String,
+}
+impl PathLanguage {
+	/// Matched language.
+	pub fn language(&self) -> Language {
+		self.language
+	}
+
+	/// File stem without the language segment, such as `movie.forced` for `movie.en.forced.srt`.
+	pub fn stem(&self) -> &str {
+		&self.stem
+	}
+}
+
+impl Language {
+	/// Find the language in a path with the default [`PathOptions`].
+	///
+	/// See [`Language::from_path_with`].
+	pub fn from_path<P>(path: P) -> Result<PathLanguage>
+	where
+		P: AsRef<Path>,
+	{
+		Self::from_path_with(path, &PathOptions::default())
+	}
+
+	/// Find the language in a path, such as `messages.fr_CA.json`, `strings/pt-BR/app.ftl` or
+	/// `README.zh-Hans.md`.
+	///
+	/// Only the last segment of the file stem is tried, after skipping
+	/// [`PathOptions::ignored_segments`], then the directories from the nearest to the root; the
+	/// extension is never a candidate, and neither are earlier segments, so `report.is.final.txt`
+	/// has no language. A segment matches when it is a canonical tag (see `TryFrom<&str>`) once its
+	/// subtag separators are replaced with `-`, so `pt-br` and `EN` do not match. Paths without a
+	/// match fail with [`Error::NoLanguageInPath`].
+	pub fn from_path_with<P>(path: P, options: &PathOptions) -> Result<PathLanguage>
+	where
+		P: AsRef<Path>,
+	{
+		let path = path.as_ref();
+		let not_found = || Error::NoLanguageInPath(path.display().to_string());
+		let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+		let mut segments = Vec::new();
+		let mut start = 0;
+
+		for (i, c) in stem.char_indices() {
+			if options.name_separators.contains(&c) {
+				segments.push(start..i);
+				start = i + c.len_utf8();
+			}
+		}
+
+		segments.push(start..stem.len());
+
+		let last = segments.into_iter().rev().find(|range| {
+			!options.ignored_segments.iter().any(|s| s.eq_ignore_ascii_case(&stem[range.clone()]))
+		});
+
+		if let Some(range) = last
+			&& let Some(language) = options.language(&stem[range.clone()])
+		{
+			// Drop the separator before the segment, or after it for the first one.
+			let removed = if range.start > 0 {
+				stem[..range.start].char_indices().last().map_or(0, |(i, _)| i)..range.end
+			} else {
+				range.start
+					..stem[range.end..]
+						.chars()
+						.next()
+						.map_or(range.end, |c| range.end + c.len_utf8())
+			};
+			let stem = format!("{}{}", &stem[..removed.start], &stem[removed.end..]);
+
+			return Ok(PathLanguage { language, stem });
+		}
+
+		let language = path
+			.parent()
+			.into_iter()
+			.flat_map(Path::components)
+			.rev()
+			.filter_map(|component| match component {
+				Component::Normal(name) => name.to_str(),
+				_ => None,
+			})
+			.find_map(|name| options.language(name))
+			.ok_or_else(not_found)?;
+
+		Ok(PathLanguage { language, stem: stem.into() })
+	}
+}
@@ -0,0 +1,311 @@
+//! CLDR plural metadata shared by the generated `Language` tables.
+
+mod rule;
+pub use rule::*;
+
+// std
+use std::{
+	fmt::{Display, Formatter, Result as FmtResult},
+	str::FromStr,
+};
+// self
+use crate::prelude::*;
+
+macro_rules! impl_from_unsigned {
+	($($ty:ty),*) => {
+		$(
+			impl From<$ty> for PluralOperands {
+				fn from(value: $ty) -> Self {
+					Self::from_integer(value as u64)
+				}
+			}
+		)*
+	};
+}
+macro_rules! impl_from_signed {
+	($($ty:ty),*) => {
+		$(
+			impl From<$ty> for PluralOperands {
+				fn from(value: $ty) -> Self {
+					Self::from_integer(value.unsigned_abs() as u64)
+				}
+			}
+		)*
+	};
+}
+
+/// CLDR plural category.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PluralCategory {
+	/// `zero`.
+	Zero,
+	/// `one`.
+	One,
+	/// `two`.
+	Two,
+	/// `few`.
+	Few,
+	/// `many`.
+	Many,
+	/// `other`.
+	Other,
+}
+impl PluralCategory {
+	/// Get all plural categories in CLDR order.
+	pub const fn all() -> [Self; 6] {
+		[Self::Zero, Self::One, Self::Two, Self::Few, Self::Many, Self::Other]
+	}
+
+	/// Get the CLDR keyword.
+	pub fn keyword(&self) -> &'static str {
+		match self {
+			Self::Zero => "zero",
+			Self::One => "one",
+			Self::Two => "two",
+			Self::Few => "few",
+			Self::Many => "many",
+			Self::Other => "other",
+		}
+	}
+}
+impl Display for PluralCategory {
+	fn fmt(&self, f: &mut Formatter) -> FmtResult {
+		f.write_str(self.keyword())
+	}
+}
+impl TryFrom<&str> for PluralCategory {
+	type Error = Error;
+
+	fn try_from(keyword: &str) -> Result<Self, Self::Error> {
+		let this = match keyword {
+			"zero" => Self::Zero,
+			"one" => Self::One,
+			"two" => Self::Two,
+			"few" => Self::Few,
+			"many" => Self::Many,
+			"other" => Self::Other,
+			_ => return Err(Error::UnsupportedPluralCategory(keyword.into())),
+		};
+
+		Ok(this)
+	}
+}
+
+/// CLDR plural operands of a formatted number.
+///
+/// See <https://unicode.org/reports/tr35/tr35-numbers.html#Operands>. The sign is ignored and an
+/// exponent (`c`/`e`) is applied to the other operands, so `1.2c3` has the operands of `1200`;
+/// exponents above `19` are rejected.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PluralOperands {
+	pub(crate) n: f64,
+	pub(crate) i: u64,
+	pub(crate) v: usize,
+	pub(crate) w: usize,
+	pub(crate) f: u64,
+	pub(crate) t: u64,
+	pub(crate) c: usize,
+}
+impl PluralOperands {
+	/// Build operands from a float formatted with exactly `precision` fraction digits.
+	///
+	/// `from_f64(1.5, 2)` has the operands of `"1.50"`.
+	pub fn from_f64(value: f64, precision: usize) -> Result<Self> {
+		if !value.is_finite() {
+			return Err(Error::InvalidPluralOperands(value.to_string()));
+		}
+
+		format!("{:.precision$}", value.abs()).parse()
+	}
+
+	/// Absolute value of the source number.
+	pub fn n(&self) -> f64 {
+		self.n
+	}
+
+	/// Integer digits of `n`.
+	pub fn i(&self) -> u64 {
+		self.i
+	}
+
+	/// Number of visible fraction digits, with trailing zeros.
+	pub fn v(&self) -> usize {
+		self.v
+	}
+
+	/// Number of visible fraction digits, without trailing zeros.
+	pub fn w(&self) -> usize {
+		self.w
+	}
+
+	/// Visible fraction digits, with trailing zeros.
+	pub fn f(&self) -> u64 {
+		self.f
+	}
+
+	/// Visible fraction digits, without trailing zeros.
+	pub fn t(&self) -> u64 {
+		self.t
+	}
+
+	/// Compact decimal exponent.
+	pub fn c(&self) -> usize {
+		self.c
+	}
+
+	/// Deprecated synonym of [`Self::c`] kept by CLDR.
+	pub fn e(&self) -> usize {
+		self.c
+	}
+
+	fn from_integer(value: u64) -> Self {
+		Self { n: value as f64, i: value, ..Default::default() }
+	}
+}
+impl FromStr for PluralOperands {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let invalid = || Error::InvalidPluralOperands(s.into());
+		let unsigned = s.strip_prefix(['-', '+']).unwrap_or(s);
+		let (mantissa, exponent) = match unsigned.split_once(['c', 'e']) {
+			Some((mantissa, exponent)) =>
+				(mantissa, exponent.parse::<usize>().map_err(|_| invalid())?),
+			None => (unsigned, 0),
+		};
+		let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+		// `u64` has at most 20 digits, so a larger exponent can never give a valid `i`; checking it
+		// first also bounds the zero padding below.
+		if exponent > 19
+			|| integer.is_empty()
+			|| !integer.bytes().all(|b| b.is_ascii_digit())
+			|| !fraction.bytes().all(|b| b.is_ascii_digit())
+			|| (mantissa.contains('.') && fraction.is_empty())
+		{
+			return Err(invalid());
+		}
+
+		// Apply the exponent by moving fraction digits into the integer part.
+		let shift = exponent.min(fraction.len());
+		let integer = format!(
+			"{integer}{}{}",
+			&fraction[..shift],
+			"0".repeat(exponent.saturating_sub(fraction.len()))
+		);
+		let fraction = &fraction[shift..];
+		let trimmed = fraction.trim_end_matches('0');
+		let parse_digits = |digits: &str| {
+			if digits.is_empty() { Ok(0) } else { digits.parse::<u64>().map_err(|_| invalid()) }
+		};
+		let n = format!("{integer}.{fraction}0").parse::<f64>().map_err(|_| invalid())?;
+
+		Ok(Self {
+			n,
+			i: parse_digits(&integer)?,
+			v: fraction.len(),
+			w: trimmed.len(),
+			f: parse_digits(fraction)?,
+			t: parse_digits(trimmed)?,
+			c: exponent,
+		})
+	}
+}
+impl TryFrom<&str> for PluralOperands {
+	type Error = Error;
+
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		value.parse()
+	}
+}
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+impl_from_signed!(i8, i16, i32, i64, isize);
+
+/// Plural compatibility of a language with the fallback whose translations it would use.
+///
+/// Translations written for the fallback only provide its categories; every category the language
+/// selects but the fallback lacks is missing. Extra fallback categories are never selected, so
+/// [`Self::is_compatible`] allows them, but they are reported for loaders that require the same
+/// categories on both sides ([`Self::is_same`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PluralCompatibility {
+	missing_cardinal: Vec<PluralCategory>,
+	missing_ordinal: Vec<PluralCategory>,
+	extra_cardinal: Vec<PluralCategory>,
+	extra_ordinal: Vec<PluralCategory>,
+}
+impl PluralCompatibility {
+	/// Whether both the cardinal and ordinal categories are covered by the fallback.
+	pub fn is_compatible(&self) -> bool {
+		self.missing_cardinal.is_empty() && self.missing_ordinal.is_empty()
+	}
+
+	/// Whether the language and the fallback have the same cardinal and ordinal categories.
+	pub fn is_same(&self) -> bool {
+		self.is_compatible() && self.extra_cardinal.is_empty() && self.extra_ordinal.is_empty()
+	}
+
+	/// Cardinal categories of the language missing from the fallback, in CLDR order.
+	pub fn missing_cardinal(&self) -> &[PluralCategory] {
+		&self.missing_cardinal
+	}
+
+	/// Ordinal categories of the language missing from the fallback, in CLDR order.
+	pub fn missing_ordinal(&self) -> &[PluralCategory] {
+		&self.missing_ordinal
+	}
+
+	/// Cardinal categories of the fallback the language never selects, in CLDR order.
+	pub fn extra_cardinal(&self) -> &[PluralCategory] {
+		&self.extra_cardinal
+	}
+
+	/// Ordinal categories of the fallback the language never selects, in CLDR order.
+	pub fn extra_ordinal(&self) -> &[PluralCategory] {
+		&self.extra_ordinal
+	}
+}
+
+/// CLDR sample numbers of a plural category.
+///
+/// Samples come from the `@integer` and `@decimal` lists of the CLDR rule, with ranges expanded.
+/// Each one parses as [`PluralOperands`]; compact numbers keep their exponent, like `1c6`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PluralSamples {
+	integers: &'static [&'static str],
+	decimals: &'static [&'static str],
+}
+impl PluralSamples {
+	pub(crate) const fn new(
+		integers: &'static [&'static str],
+		decimals: &'static [&'static str],
+	) -> Self {
+		Self { integers, decimals }
+	}
+
+	/// Integer samples, such as `1`, `21`, `31`.
+	pub fn integers(&self) -> &'static [&'static str] {
+		self.integers
+	}
+
+	/// Decimal samples, such as `0.1`, `1.5`.
+	pub fn decimals(&self) -> &'static [&'static str] {
+		self.decimals
+	}
+}
+
+impl Language {
+	/// Check whether translations for `fallback` cover every plural category of this language.
+	pub fn plural_compatibility(&self, fallback: Self) -> PluralCompatibility {
+		let missing = |categories: &[PluralCategory], available: &[PluralCategory]| {
+			categories.iter().copied().filter(|category| !available.contains(category)).collect()
+		};
+
+		PluralCompatibility {
+			missing_cardinal: missing(self.plural_categories(), fallback.plural_categories()),
+			missing_ordinal: missing(self.ordinal_categories(), fallback.ordinal_categories()),
+			extra_cardinal: missing(fallback.plural_categories(), self.plural_categories()),
+			extra_ordinal: missing(fallback.ordinal_categories(), self.ordinal_categories()),
+		}
+	}
+}
@@ -0,0 +1,432 @@
+//! CLDR plural rule syntax.
+//!
+//! See <https://unicode.org/reports/tr35/tr35-numbers.html#Plural_rules_syntax>.
+
+// std
+use std::str::FromStr;
+// self
+use crate::prelude::*;
+
+// Most samples one list may expand to; CLDR lists stay far below, and the cap keeps
+// `PluralSampleList::values` and `PluralRules::validate` cheap on untrusted rules.
+const MAX_SAMPLES: u128 = 1_000;
+
+/// Operand referenced by a plural rule relation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluralOperand {
+	/// `n`, the absolute value of the number.
+	N,
+	/// `i`, the integer digits.
+	I,
+	/// `v`, the number of visible fraction digits with trailing zeros.
+	V,
+	/// `w`, the number of visible fraction digits without trailing zeros.
+	W,
+	/// `f`, the visible fraction digits with trailing zeros.
+	F,
+	/// `t`, the visible fraction digits without trailing zeros.
+	T,
+	/// `c`, the compact decimal exponent.
+	C,
+	/// `e`, the deprecated synonym of `c`.
+	E,
+}
+impl PluralOperand {
+	/// Get the operand symbol.
+	pub fn symbol(&self) -> char {
+		match self {
+			Self::N => 'n',
+			Self::I => 'i',
+			Self::V => 'v',
+			Self::W => 'w',
+			Self::F => 'f',
+			Self::T => 't',
+			Self::C => 'c',
+			Self::E => 'e',
+		}
+	}
+
+	fn parse(symbol: &str) -> Option<Self> {
+		let this = match symbol {
+			"n" => Self::N,
+			"i" => Self::I,
+			"v" => Self::V,
+			"w" => Self::W,
+			"f" => Self::F,
+			"t" => Self::T,
+			"c" => Self::C,
+			"e" => Self::E,
+			_ => return None,
+		};
+
+		Some(this)
+	}
+
+	// `n` only takes part in integer ranges, so a number with fraction digits has no value here.
+	fn integer_value(&self, operands: &PluralOperands) -> Option<u64> {
+		let value = match self {
+			Self::N if operands.t != 0 => return None,
+			Self::N | Self::I => operands.i,
+			Self::V => operands.v as u64,
+			Self::W => operands.w as u64,
+			Self::F => operands.f,
+			Self::T => operands.t,
+			Self::C | Self::E => operands.c as u64,
+		};
+
+		Some(value)
+	}
+}
+
+/// Single relation of a plural rule, such as `n % 100 != 11..13`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PluralRelation {
+	operand: PluralOperand,
+	modulus: Option<u64>,
+	negated: bool,
+	ranges: Vec<(u64, u64)>,
+}
+impl PluralRelation {
+	/// Operand on the left-hand side.
+	pub fn operand(&self) -> PluralOperand {
+		self.operand
+	}
+
+	/// Modulus applied to the operand, if any.
+	pub fn modulus(&self) -> Option<u64> {
+		self.modulus
+	}
+
+	/// Whether the relation uses `!=`.
+	pub fn negated(&self) -> bool {
+		self.negated
+	}
+
+	/// Inclusive ranges on the right-hand side, in source order; single values have equal bounds.
+	pub fn ranges(&self) -> &[(u64, u64)] {
+		&self.ranges
+	}
+
+	/// Evaluate the relation.
+	pub fn matches(&self, operands: &PluralOperands) -> bool {
+		let contained = self.operand.integer_value(operands).is_some_and(|value| {
+			let value = match self.modulus {
+				Some(modulus) => value % modulus,
+				None => value,
+			};
+
+			self.ranges.iter().any(|(start, end)| (*start..=*end).contains(&value))
+		});
+
+		contained != self.negated
+	}
+}
+
+/// Sample list of a plural rule, such as `@integer 0~15, 100, …`.
+///
+/// A list expands to at most 1000 samples; longer lists are rejected when parsing the rule.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PluralSampleList {
+	ranges: Vec<(String, String)>,
+	unbounded: bool,
+}
+impl PluralSampleList {
+	/// Sample ranges in source order; single samples have equal bounds.
+	pub fn ranges(&self) -> &[(String, String)] {
+		&self.ranges
+	}
+
+	/// Whether the list ends with `…`, meaning more numbers match than listed.
+	pub fn is_unbounded(&self) -> bool {
+		self.unbounded
+	}
+
+	/// Expand every range into its samples.
+	///
+	/// A range steps by the last visible digit, so `0.0~0.2` yields `0.0`, `0.1` and `0.2`.
+	pub fn values(&self) -> Vec<String> {
+		let mut values = Vec::new();
+
+		for (start, end) in &self.ranges {
+			match (scaled(start), scaled(end)) {
+				(Some((first, precision)), Some((last, _))) if start != end =>
+					values.extend((first..=last).map(|value| unscaled(value, precision))),
+				_ => values.push(start.clone()),
+			}
+		}
+
+		values
+	}
+
+	fn parse(text: &str) -> Option<Self> {
+		let mut this = Self::default();
+		let mut count = 0;
+
+		for sample in text.split(',').map(str::trim) {
+			if this.unbounded {
+				return None;
+			}
+			if sample == "…" || sample == "..." {
+				this.unbounded = true;
+
+				continue;
+			}
+
+			let (start, end) = sample.split_once('~').unwrap_or((sample, sample));
+			let (start, end) = (start.trim(), end.trim());
+
+			PluralOperands::from_str(start).ok()?;
+			PluralOperands::from_str(end).ok()?;
+
+			// Ranges must share their precision so they can be stepped digit by digit.
+			if start != end {
+				let ((first, precision), (last, end_precision)) = (scaled(start)?, scaled(end)?);
+
+				if precision != end_precision || first > last {
+					return None;
+				}
+
+				count += last - first;
+			}
+
+			count += 1;
+
+			if count > MAX_SAMPLES {
+				return None;
+			}
+
+			this.ranges.push((start.into(), end.into()));
+		}
+
+		(!this.ranges.is_empty()).then_some(this)
+	}
+}
+
+/// Parsed CLDR plural rule: a condition plus optional `@integer` and `@decimal` samples.
+///
+/// An empty condition always matches, as used by `other`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PluralRule {
+	condition: Vec<Vec<PluralRelation>>,
+	integer_samples: Option<PluralSampleList>,
+	decimal_samples: Option<PluralSampleList>,
+}
+impl PluralRule {
+	/// Condition in disjunctive normal form: `or` of `and` relations.
+	pub fn condition(&self) -> &[Vec<PluralRelation>] {
+		&self.condition
+	}
+
+	/// Samples after `@integer`.
+	pub fn integer_samples(&self) -> Option<&PluralSampleList> {
+		self.integer_samples.as_ref()
+	}
+
+	/// Samples after `@decimal`.
+	pub fn decimal_samples(&self) -> Option<&PluralSampleList> {
+		self.decimal_samples.as_ref()
+	}
+
+	/// Evaluate the condition.
+	pub fn matches(&self, operands: &PluralOperands) -> bool {
+		self.condition.is_empty()
+			|| self.condition.iter().any(|and_condition| {
+				and_condition.iter().all(|relation| relation.matches(operands))
+			})
+	}
+}
+impl FromStr for PluralRule {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let invalid = || Error::InvalidPluralRule(s.trim().into());
+		let mut sections = s.split('@');
+		let condition = parse_condition(sections.next().unwrap_or_default()).ok_or_else(invalid)?;
+		let mut this = Self { condition, ..Default::default() };
+
+		for section in sections {
+			let (kind, list) = section.split_once(char::is_whitespace).unwrap_or((section, ""));
+			// `@integer` comes before `@decimal` and each appears at most once.
+			let slot = match kind {
+				"integer" if this.integer_samples.is_none() && this.decimal_samples.is_none() =>
+					&mut this.integer_samples,
+				"decimal" if this.decimal_samples.is_none() => &mut this.decimal_samples,
+				_ => return Err(invalid()),
+			};
+
+			*slot = Some(PluralSampleList::parse(list).ok_or_else(invalid)?);
+		}
+
+		Ok(this)
+	}
+}
+impl TryFrom<&str> for PluralRule {
+	type Error = Error;
+
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		value.parse()
+	}
+}
+
+/// Ordered CLDR plural rules of one locale, ending with `other`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PluralRules {
+	rules: Vec<(PluralCategory, PluralRule)>,
+}
+impl PluralRules {
+	/// Parse `(keyword, rule)` pairs, as found in `<pluralRule count="…">` elements.
+	///
+	/// Categories must be unique and `other` must come last.
+	pub fn parse<'a, I>(rules: I) -> Result<Self>
+	where
+		I: IntoIterator<Item = (&'a str, &'a str)>,
+	{
+		let mut parsed = Vec::<(PluralCategory, PluralRule)>::new();
+
+		for (keyword, rule) in rules {
+			let category = PluralCategory::try_from(keyword)?;
+
+			if parsed.iter().any(|(seen, _)| *seen == category) {
+				return Err(Error::MalformedPluralRules);
+			}
+
+			parsed.push((category, rule.parse()?));
+		}
+
+		match parsed.last() {
+			Some((PluralCategory::Other, _)) => Ok(Self { rules: parsed }),
+			_ => Err(Error::MalformedPluralRules),
+		}
+	}
+
+	/// Rules in evaluation order.
+	pub fn rules(&self) -> &[(PluralCategory, PluralRule)] {
+		&self.rules
+	}
+
+	/// Categories in evaluation order.
+	pub fn categories(&self) -> impl Iterator<Item = PluralCategory> + '_ {
+		self.rules.iter().map(|(category, _)| *category)
+	}
+
+	/// Select the category of the first matching rule.
+	pub fn select(&self, operands: &PluralOperands) -> PluralCategory {
+		self.rules
+			.iter()
+			.find(|(_, rule)| rule.matches(operands))
+			.map_or(PluralCategory::Other, |(category, _)| *category)
+	}
+
+	/// Check that every listed sample selects the category of its rule.
+	pub fn validate(&self) -> Result<()> {
+		for (expected, rule) in &self.rules {
+			let samples = rule.integer_samples.iter().chain(&rule.decimal_samples);
+
+			for sample in samples.flat_map(PluralSampleList::values) {
+				let actual = self.select(&sample.parse()?);
+
+				if actual != *expected {
+					return Err(Error::PluralSampleMismatch {
+						sample,
+						expected: *expected,
+						actual,
+					});
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+fn parse_condition(text: &str) -> Option<Vec<Vec<PluralRelation>>> {
+	let tokens = tokenize(text)?;
+
+	if tokens.is_empty() {
+		return Some(Vec::new());
+	}
+
+	tokens
+		.split(|token| *token == "or")
+		.map(|and_condition| {
+			and_condition.split(|token| *token == "and").map(parse_relation).collect()
+		})
+		.collect()
+}
+
+fn parse_relation(tokens: &[&str]) -> Option<PluralRelation> {
+	let (operand, modulus, rest) = match tokens {
+		[operand, "%" | "mod", modulus, rest @ ..] => (*operand, Some(modulus.parse().ok()?), rest),
+		[operand, rest @ ..] => (*operand, None, rest),
+		[] => return None,
+	};
+	let operand = PluralOperand::parse(operand)?;
+	let (negated, ranges) = match rest {
+		["=", ranges @ ..] => (false, ranges),
+		["!=", ranges @ ..] => (true, ranges),
+		_ => return None,
+	};
+
+	if modulus == Some(0) || ranges.is_empty() {
+		return None;
+	}
+
+	let ranges = ranges
+		.split(|token| *token == ",")
+		.map(|range| match range {
+			[value] => {
+				let value = value.parse().ok()?;
+
+				Some((value, value))
+			},
+			[start, "..", end] => {
+				let (start, end) = (start.parse().ok()?, end.parse().ok()?);
+
+				(start <= end).then_some((start, end))
+			},
+			_ => None,
+		})
+		.collect::<Option<Vec<_>>>()?;
+
+	Some(PluralRelation { operand, modulus, negated, ranges })
+}
+
+fn tokenize(text: &str) -> Option<Vec<&str>> {
+	let mut tokens = Vec::new();
+	let mut rest = text.trim_start();
+
+	while let Some(ch) = rest.chars().next() {
+		let len = if ch.is_ascii_alphanumeric() {
+			rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(rest.len())
+		} else if rest.starts_with("!=") || rest.starts_with("..") {
+			2
+		} else if matches!(ch, '%' | '=' | ',') {
+			1
+		} else {
+			return None;
+		};
+
+		tokens.push(&rest[..len]);
+		rest = rest[len..].trim_start();
+	}
+
+	Some(tokens)
+}
+
+// Split a sample into its digits and precision, so `1.50` becomes `(150, 2)`.
+fn scaled(sample: &str) -> Option<(u128, usize)> {
+	if sample.contains(['c', 'e']) {
+		return None;
+	}
+
+	let (integer, fraction) = sample.split_once('.').unwrap_or((sample, ""));
+
+	Some((format!("{integer}{fraction}").parse().ok()?, fraction.len()))
+}
+
+fn unscaled(value: u128, precision: usize) -> String {
+	let digits = format!("{value:0>width$}", width = precision + 1);
+	let (integer, fraction) = digits.split_at(digits.len() - precision);
+
+	if fraction.is_empty() { integer.into() } else { format!("{integer}.{fraction}") }
+}
@@ -0,0 +1,99 @@
+// self
+use crate::{alias, prelude::*};
+
+// POSIX modifiers that name a script, paired with the ISO 15924 code used in tags. glibc only
+// defines the first two; the others keep the script of `zh-Hans`, `zh-Hant` and `mn-Mong-CN`.
+const SCRIPT_MODIFIERS: [(&str, &str); 5] = [
+	("latin", "Latn"),
+	("cyrillic", "Cyrl"),
+	("hans", "Hans"),
+	("hant", "Hant"),
+	("mongolian", "Mong"),
+];
+
+impl Language {
+	/// Parse a POSIX locale identifier such as `pt_BR.UTF-8` or `sr_RS@latin`.
+	///
+	/// The codeset is ignored, script modifiers (`@latin`, `@cyrillic`, `@hans`, `@hant`,
+	/// `@mongolian`) select the matching script variant and other modifiers (like `@euro`) are
+	/// ignored. When no variant has the exact territory, the language without it is used, keeping
+	/// the script. Without a modifier no script is assumed, so `sr_RS` gives [`Language::Sr`]
+	/// rather than glibc's Cyrillic default ([`Language::SrCyrlSp`]). `C` and `POSIX` map to
+	/// [`Language::En`].
+	pub fn from_posix(locale: &str) -> Result<Self> {
+		let unsupported = || Error::UnsupportedPosixLocale(locale.into());
+		let (locale_name, modifier) = locale.trim().split_once('@').unwrap_or((locale.trim(), ""));
+		let name = locale_name.split_once('.').map_or(locale_name, |(name, _)| name);
+
+		if matches!(name, "C" | "POSIX") {
+			return Ok(Self::En);
+		}
+
+		let (language, territory) = name.split_once('_').unwrap_or((name, ""));
+		let is_alpha = |s: &str, lengths: &[usize]| {
+			lengths.contains(&s.len()) && s.bytes().all(|b| b.is_ascii_alphabetic())
+		};
+		let is_numeric_region =
+			territory.len() == 3 && territory.bytes().all(|b| b.is_ascii_digit());
+
+		if !is_alpha(language, &[2, 3])
+			|| !(territory.is_empty() || is_alpha(territory, &[2]) || is_numeric_region)
+		{
+			return Err(unsupported());
+		}
+
+		let language = language.to_ascii_lowercase();
+		let territory = territory.to_ascii_uppercase();
+		let script = SCRIPT_MODIFIERS
+			.into_iter()
+			.find(|(posix, _)| posix.eq_ignore_ascii_case(modifier))
+			.map(|(_, script)| script);
+		let base = match script {
+			Some(script) => format!("{language}-{script}"),
+			None => language,
+		};
+		let mut candidates = vec![base.clone()];
+
+		if !territory.is_empty() {
+			candidates.insert(0, format!("{base}-{territory}"));
+		}
+
+		candidates
+			.iter()
+			.find_map(|candidate| Self::parse_with_aliases(candidate).ok())
+			.map(|(language, _)| language)
+			.ok_or_else(unsupported)
+	}
+
+	/// Format as a POSIX locale identifier without codeset, such as `pt_BR`, `sr_RS@latin` or
+	/// `zh@hant`.
+	///
+	/// Scripts are written as modifiers, so every language round-trips through
+	/// [`Language::from_posix`].
+	pub fn to_posix(&self) -> String {
+		let mut subtags = self.tag().split('-');
+		let mut posix = subtags.next().unwrap_or_default().to_owned();
+		let mut modifier = None;
+
+		for subtag in subtags {
+			if subtag.len() == 4 {
+				modifier = SCRIPT_MODIFIERS
+					.into_iter()
+					.find(|(_, script)| *script == subtag)
+					.map(|(posix, _)| posix);
+			} else {
+				let region = alias::preferred_region(subtag).unwrap_or(subtag);
+
+				posix.push('_');
+				posix.push_str(region);
+			}
+		}
+
+		if let Some(modifier) = modifier {
+			posix.push('@');
+			posix.push_str(modifier);
+		}
+
+		posix
+	}
+}
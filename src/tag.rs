@@ -0,0 +1,268 @@
+//! Owned RFC 5646 (BCP47) language tags.
+
+// std
+use std::{
+	fmt::{Display, Formatter, Result as FmtResult},
+	str::FromStr,
+};
+// self
+use crate::prelude::*;
+
+// RFC 5646 section 2.2.8; irregular tags first, then the regular ones.
+const GRANDFATHERED: [&str; 26] = [
+	"en-GB-oed",
+	"i-ami",
+	"i-bnn",
+	"i-default",
+	"i-enochian",
+	"i-hak",
+	"i-klingon",
+	"i-lux",
+	"i-mingo",
+	"i-navajo",
+	"i-pwn",
+	"i-tao",
+	"i-tay",
+	"i-tsu",
+	"sgn-BE-FR",
+	"sgn-BE-NL",
+	"sgn-CH-DE",
+	"art-lojban",
+	"cel-gaulish",
+	"no-bok",
+	"no-nyn",
+	"zh-guoyu",
+	"zh-hakka",
+	"zh-min",
+	"zh-min-nan",
+	"zh-xiang",
+];
+
+/// Extension of a language tag, such as `u-ca-buddhist`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TagExtension {
+	singleton: char,
+	subtags: Vec<String>,
+}
+impl TagExtension {
+	/// Singleton introducing the extension, in lowercase.
+	pub fn singleton(&self) -> char {
+		self.singleton
+	}
+
+	/// Subtags after the singleton, in lowercase.
+	pub fn subtags(&self) -> &[String] {
+		&self.subtags
+	}
+}
+
+/// Well-formed RFC 5646 language tag.
+///
+/// Parsing is case-insensitive and only checks the syntax, not the IANA registry. Subtags are
+/// stored with the RFC 5646 case conventions: lowercase language, titlecase script, uppercase
+/// region and lowercase everything else. Grandfathered tags keep their registered spelling and have
+/// no subtag accessors, and private-use tags (`x-…`) only have [`Self::private_use`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LanguageTag {
+	pub(crate) language: Option<String>,
+	pub(crate) extlangs: Vec<String>,
+	pub(crate) script: Option<String>,
+	pub(crate) region: Option<String>,
+	pub(crate) variants: Vec<String>,
+	pub(crate) extensions: Vec<TagExtension>,
+	pub(crate) private_use: Vec<String>,
+	pub(crate) grandfathered: Option<&'static str>,
+}
+impl LanguageTag {
+	/// Primary language subtag, or `None` for private-use and grandfathered tags.
+	pub fn language(&self) -> Option<&str> {
+		self.language.as_deref()
+	}
+
+	/// Extended language subtags.
+	pub fn extlangs(&self) -> &[String] {
+		&self.extlangs
+	}
+
+	/// Script subtag.
+	pub fn script(&self) -> Option<&str> {
+		self.script.as_deref()
+	}
+
+	/// Region subtag.
+	pub fn region(&self) -> Option<&str> {
+		self.region.as_deref()
+	}
+
+	/// Variant subtags.
+	pub fn variants(&self) -> &[String] {
+		&self.variants
+	}
+
+	/// Extensions in source order.
+	pub fn extensions(&self) -> &[TagExtension] {
+		&self.extensions
+	}
+
+	/// Subtags after `x`.
+	pub fn private_use(&self) -> &[String] {
+		&self.private_use
+	}
+
+	/// Whether the tag is one of the grandfathered tags of RFC 5646.
+	pub fn is_grandfathered(&self) -> bool {
+		self.grandfathered.is_some()
+	}
+
+	/// Whether the tag only holds private-use subtags, such as `x-whatever`.
+	pub fn is_private_use(&self) -> bool {
+		self.language.is_none() && self.grandfathered.is_none()
+	}
+}
+impl Display for LanguageTag {
+	fn fmt(&self, f: &mut Formatter) -> FmtResult {
+		if let Some(grandfathered) = self.grandfathered {
+			return f.write_str(grandfathered);
+		}
+
+		let mut subtags = Vec::new();
+
+		subtags.extend(self.language.as_deref());
+		subtags.extend(self.extlangs.iter().map(String::as_str));
+		subtags.extend(self.script.as_deref());
+		subtags.extend(self.region.as_deref());
+		subtags.extend(self.variants.iter().map(String::as_str));
+
+		let singletons =
+			self.extensions.iter().map(|e| e.singleton.to_string()).collect::<Vec<_>>();
+
+		for (extension, singleton) in self.extensions.iter().zip(&singletons) {
+			subtags.push(singleton);
+			subtags.extend(extension.subtags.iter().map(String::as_str));
+		}
+		if !self.private_use.is_empty() {
+			subtags.push("x");
+			subtags.extend(self.private_use.iter().map(String::as_str));
+		}
+
+		f.write_str(&subtags.join("-"))
+	}
+}
+impl FromStr for LanguageTag {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let invalid = || Error::InvalidLanguageTag(s.into());
+
+		if let Some(grandfathered) =
+			GRANDFATHERED.into_iter().find(|tag| tag.eq_ignore_ascii_case(s))
+		{
+			return Ok(Self { grandfathered: Some(grandfathered), ..Default::default() });
+		}
+
+		let subtags = s.split('-').map(str::to_ascii_lowercase).collect::<Vec<_>>();
+
+		if subtags.iter().any(|subtag| {
+			!(1..=8).contains(&subtag.len()) || !subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+		}) {
+			return Err(invalid());
+		}
+
+		let mut this = Self::default();
+		let mut rest = subtags.as_slice();
+		let is_alpha = |subtag: &str| subtag.bytes().all(|b| b.is_ascii_alphabetic());
+		let is_digit = |subtag: &str| subtag.bytes().all(|b| b.is_ascii_digit());
+
+		if let [language, tail @ ..] = rest
+			&& language != "x"
+		{
+			if !(2..=8).contains(&language.len()) || !is_alpha(language) {
+				return Err(invalid());
+			}
+
+			this.language = Some(language.clone());
+			rest = tail;
+
+			// Extended language subtags only follow a 2-3 letter language.
+			if language.len() <= 3 {
+				while let [extlang, tail @ ..] = rest
+					&& extlang.len() == 3
+					&& is_alpha(extlang)
+					&& this.extlangs.len() < 3
+				{
+					this.extlangs.push(extlang.clone());
+					rest = tail;
+				}
+			}
+			if let [script, tail @ ..] = rest
+				&& script.len() == 4
+				&& is_alpha(script)
+			{
+				this.script = Some(format!("{}{}", script[..1].to_ascii_uppercase(), &script[1..]));
+				rest = tail;
+			}
+			if let [region, tail @ ..] = rest
+				&& ((region.len() == 2 && is_alpha(region))
+					|| (region.len() == 3 && is_digit(region)))
+			{
+				this.region = Some(region.to_ascii_uppercase());
+				rest = tail;
+			}
+
+			while let [variant, tail @ ..] = rest
+				&& (variant.len() >= 5
+					|| (variant.len() == 4 && variant.as_bytes()[0].is_ascii_digit()))
+			{
+				this.variants.push(variant.clone());
+				rest = tail;
+			}
+			while let [singleton, tail @ ..] = rest
+				&& singleton.len() == 1
+				&& singleton != "x"
+			{
+				let count = tail.iter().take_while(|subtag| subtag.len() >= 2).count();
+
+				if count == 0 {
+					return Err(invalid());
+				}
+
+				this.extensions.push(TagExtension {
+					singleton: singleton.as_bytes()[0].into(),
+					subtags: tail[..count].to_vec(),
+				});
+				rest = &tail[count..];
+			}
+		}
+
+		match rest {
+			[] if this.language.is_some() => Ok(this),
+			[x, private_use @ ..] if x == "x" && !private_use.is_empty() => {
+				this.private_use = private_use.to_vec();
+
+				Ok(this)
+			},
+			_ => Err(invalid()),
+		}
+	}
+}
+impl TryFrom<&str> for LanguageTag {
+	type Error = Error;
+
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		value.parse()
+	}
+}
+impl From<Language> for LanguageTag {
+	fn from(value: Language) -> Self {
+		value.tag().parse().expect("generated tags are well-formed")
+	}
+}
+impl TryFrom<&LanguageTag> for Language {
+	type Error = Error;
+
+	fn try_from(value: &LanguageTag) -> Result<Self, Self::Error> {
+		let tag = value.to_string();
+
+		Language::try_from(tag.as_str()).map_err(|_| Error::UnsupportedLanguageTag(tag))
+	}
+}
@@ -0,0 +1,142 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn accept_language_should_order_ranges_by_quality() {
+	let header = AcceptLanguage::from("fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5");
+	let ranges = header
+		.ranges()
+		.iter()
+		.map(|range| (range.range(), range.quality(), range.language()))
+		.collect::<Vec<_>>();
+
+	assert_eq!(
+		ranges,
+		[
+			("fr-CH", 1_000, Some(Language::FrCh)),
+			("fr", 900, Some(Language::Fr)),
+			("en", 800, Some(Language::En)),
+			("*", 500, None),
+		]
+	);
+	assert!(header.ranges()[3].is_wildcard());
+}
+
+#[test]
+fn accept_language_should_tolerate_whitespace_and_malformed_weights() {
+	let header = AcceptLanguage::from(
+		"Accept-Language:  de ; q=0.5 ,, en-us;Q=0.7 , x-klingon;q=1, ja;q=2, ko;q=high, \
+		 pt-BR;q=0.1234, es;level=1, it;q=0, zh-hk;q=1.000, !!;q=0.9",
+	);
+	let ranges = header
+		.ranges()
+		.iter()
+		.map(|range| (range.range(), range.quality(), range.language()))
+		.collect::<Vec<_>>();
+
+	assert_eq!(
+		ranges,
+		[
+			("x-klingon", 1_000, None),
+			("es", 1_000, Some(Language::Es)),
+			("zh-hk", 1_000, Some(Language::ZhHk)),
+			("en-us", 700, Some(Language::EnUs)),
+			("de", 500, Some(Language::De)),
+			("it", 0, Some(Language::It)),
+		]
+	);
+	assert!(AcceptLanguage::from("").is_empty());
+	assert!(AcceptLanguage::from(" , ").is_empty());
+}
+
+#[test]
+fn negotiate_should_honour_quality_and_fallbacks() {
+	for (header, supported, language, reason, range) in [
+		(
+			"fr-CH, fr;q=0.9, en;q=0.8",
+			&[Language::En, Language::Fr][..],
+			Language::Fr,
+			NegotiationReason::Fallback,
+			Some("fr-CH"),
+		),
+		(
+			"fr-CH, en;q=0.8",
+			&[Language::En, Language::FrFr, Language::FrCa],
+			Language::FrFr,
+			NegotiationReason::Fallback,
+			Some("fr-CH"),
+		),
+		(
+			"fr-CH, en;q=0.8",
+			&[Language::FrCa, Language::Fr, Language::En],
+			Language::Fr,
+			NegotiationReason::Fallback,
+			Some("fr-CH"),
+		),
+		(
+			"de;q=0.5, iw",
+			&[Language::De, Language::He],
+			Language::He,
+			NegotiationReason::Exact,
+			Some("iw"),
+		),
+		(
+			"zh-TW",
+			&[Language::ZhHans, Language::ZhHant],
+			Language::ZhHant,
+			NegotiationReason::ScriptEquivalent,
+			Some("zh-TW"),
+		),
+		(
+			"zh-Hant",
+			&[Language::ZhCn, Language::ZhTw],
+			Language::ZhTw,
+			NegotiationReason::ScriptEquivalent,
+			Some("zh-Hant"),
+		),
+		(
+			"zh-TW",
+			&[Language::ZhHans, Language::ZhHk],
+			Language::ZhHk,
+			NegotiationReason::Fallback,
+			Some("zh-TW"),
+		),
+		(
+			"en;q=0, *;q=0.1",
+			&[Language::En, Language::EnUs, Language::De],
+			Language::De,
+			NegotiationReason::Wildcard,
+			Some("*"),
+		),
+		("ja, *;q=0", &[Language::En], Language::Es, NegotiationReason::Default, None),
+		("ja, *;q=0", &[Language::Ja], Language::Ja, NegotiationReason::Exact, Some("ja")),
+		(
+			"en-US, en;q=0",
+			&[Language::EnUs],
+			Language::EnUs,
+			NegotiationReason::Exact,
+			Some("en-US"),
+		),
+		("en-US, en;q=0", &[Language::En], Language::Es, NegotiationReason::Default, None),
+		(
+			"iw;q=0, *",
+			&[Language::He, Language::En],
+			Language::En,
+			NegotiationReason::Wildcard,
+			Some("*"),
+		),
+		("", &[Language::En], Language::Es, NegotiationReason::Default, None),
+	] {
+		let negotiation = negotiate(header, supported, Language::Es);
+
+		assert_eq!(
+			(
+				negotiation.language(),
+				negotiation.reason(),
+				negotiation.range().map(LanguageRange::range)
+			),
+			(language, reason, range),
+			"{header}"
+		);
+	}
+}
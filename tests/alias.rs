@@ -0,0 +1,61 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn parse_with_aliases_should_replace_deprecated_subtags() {
+	for (input, expected, applied) in [
+		("iw", Language::He, vec![("iw", "he")]),
+		("in", Language::Id, vec![("in", "id")]),
+		("jw", Language::Jv, vec![("jw", "jv")]),
+		("MO", Language::Ro, vec![("mo", "ro")]),
+		("no_NO", Language::NbNo, vec![("no", "nb")]),
+		// The dataset keeps the retired `SP`, whose replacement is reported as well.
+		("sr-Cyrl-RS", Language::SrCyrlSp, vec![("SP", "RS")]),
+		("sr-latn-yu", Language::SrLatnSp, vec![("YU", "RS"), ("SP", "RS")]),
+		("sh-CS", Language::SrLatnSp, vec![("sh", "sr-Latn"), ("CS", "RS"), ("SP", "RS")]),
+		("sh", Language::SrLatnSp, vec![("sh", "sr-Latn")]),
+		("SH-rs", Language::SrLatnSp, vec![("sh", "sr-Latn"), ("SP", "RS")]),
+		// Tags present in the dataset are kept even when deprecated.
+		("no", Language::No, vec![]),
+		("tl", Language::Tl, vec![]),
+		("sr-Cyrl-SP", Language::SrCyrlSp, vec![]),
+	] {
+		let (language, aliases) = Language::parse_with_aliases(input).unwrap();
+		let aliases =
+			aliases.iter().map(|alias| (alias.deprecated(), alias.preferred())).collect::<Vec<_>>();
+
+		assert_eq!((language, aliases), (expected, applied), "{input}");
+		assert_eq!(Language::parse_lenient(input).unwrap(), expected, "{input}");
+	}
+
+	for invalid in ["", "iw-", "sh-Cyrl-BA", "xx-YU"] {
+		assert!(
+			matches!(Language::parse_with_aliases(invalid), Err(Error::UnsupportedLanguageTag(tag)) if tag == invalid)
+		);
+	}
+
+	// The strict path does not apply aliases.
+	assert!(Language::try_from("iw").is_err());
+}
+
+#[test]
+fn from_posix_should_apply_aliases() {
+	assert_eq!(Language::from_posix("iw_IL.UTF-8").unwrap(), Language::He);
+	assert_eq!(Language::from_posix("sr_YU@latin").unwrap(), Language::SrLatnSp);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_should_accept_aliases() {
+	#[derive(serde::Deserialize)]
+	struct Catalog {
+		#[serde(deserialize_with = "language::alias::deserialize")]
+		language: Language,
+	}
+
+	let catalog = serde_json::from_str::<Catalog>(r#"{ "language": "iw" }"#).unwrap();
+
+	assert_eq!(catalog.language, Language::He);
+	assert!(serde_json::from_str::<Catalog>(r#"{ "language": "xx" }"#).is_err());
+	assert!(serde_json::from_str::<Language>(r#""iw""#).is_err());
+}
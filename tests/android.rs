@@ -0,0 +1,57 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn android_qualifier_should_roundtrip() {
+	for (language, qualifier) in [
+		(Language::En, "en"),
+		(Language::PtBr, "pt-rBR"),
+		(Language::ZhTw, "zh-rTW"),
+		(Language::ZhHant, "b+zh+Hant"),
+		(Language::SrLatnSp, "b+sr+Latn+RS"),
+		(Language::En029, "b+en+029"),
+		(Language::Fil, "b+fil"),
+	] {
+		assert_eq!(language.android_qualifier(), qualifier);
+	}
+
+	for language in Language::all() {
+		let qualifier = language.android_qualifier();
+
+		assert_eq!(Language::from_android_qualifier(&qualifier).unwrap(), language, "{qualifier}");
+		assert_eq!(
+			Language::from_android_qualifier(&format!("values-{qualifier}")).unwrap(),
+			language,
+			"{qualifier}"
+		);
+	}
+}
+
+#[test]
+fn from_android_qualifier_should_accept_resource_directories() {
+	for (qualifier, expected) in [
+		("values-zh-rTW", Language::ZhTw),
+		("values-b+sr+Latn", Language::SrLatnSp),
+		("values-iw", Language::He),
+		("values-pt-rBR-night", Language::PtBr),
+		("drawable-de-rAT", Language::DeAt),
+		("values-b+es+419", Language::Es),
+		("raw-fr", Language::Fr),
+		("xml-fr", Language::Fr),
+		("raw-b+sr+Latn", Language::SrLatnSp),
+		("values-mcc310-mnc004-en-rUS", Language::EnUs),
+		("mcc208-fr", Language::Fr),
+	] {
+		assert_eq!(Language::from_android_qualifier(qualifier).unwrap(), expected, "{qualifier}");
+	}
+
+	for invalid in ["", "values", "values-night", "values-xx", "values-b+", "raw", "raw-night"] {
+		assert!(
+			matches!(
+				Language::from_android_qualifier(invalid),
+				Err(Error::UnsupportedAndroidQualifier(qualifier)) if qualifier == invalid
+			),
+			"{invalid}"
+		);
+	}
+}
@@ -0,0 +1,52 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn apple_locale_should_roundtrip() {
+	for (language, locale) in [
+		(Language::PtBr, "pt-BR"),
+		(Language::ZhHans, "zh-Hans"),
+		(Language::ZhCn, "zh-Hans"),
+		(Language::ZhTw, "zh-Hant"),
+		(Language::ZhSg, "zh-Hans-SG"),
+		(Language::ZhHk, "zh-HK"),
+		(Language::SrCyrlSp, "sr-Cyrl-RS"),
+	] {
+		assert_eq!(language.apple_locale(), locale);
+	}
+
+	assert_eq!(Language::PtBr.lproj(), "pt-BR.lproj");
+
+	for language in Language::all() {
+		if matches!(language, Language::ZhCn | Language::ZhTw) {
+			continue;
+		}
+
+		assert_eq!(Language::from_apple_locale(&language.lproj()).unwrap(), language);
+	}
+}
+
+#[test]
+fn from_apple_locale_should_accept_identifiers_and_legacy_names() {
+	for (locale, expected) in [
+		("pt-BR.lproj", Language::PtBr),
+		("pt_BR", Language::PtBr),
+		("zh-Hans.lproj", Language::ZhHans),
+		("zh-Hant-TW", Language::ZhTw),
+		("zh_Hant_MO", Language::ZhMo),
+		("English.lproj", Language::En),
+		("Japanese.lproj", Language::Ja),
+	] {
+		assert_eq!(Language::from_apple_locale(locale).unwrap(), expected, "{locale}");
+	}
+
+	for invalid in ["", "Base.lproj", "xx.lproj", ".lproj"] {
+		assert!(
+			matches!(
+				Language::from_apple_locale(invalid),
+				Err(Error::UnsupportedAppleLocale(locale)) if locale == invalid
+			),
+			"{invalid}"
+		);
+	}
+}
@@ -0,0 +1,44 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn dotnet_culture_should_roundtrip() {
+	for (language, culture) in [
+		(Language::ZhTw, "zh-TW"),
+		(Language::ZhHans, "zh-Hans"),
+		(Language::SrLatnSp, "sr-Latn-RS"),
+		(Language::En029, "en-029"),
+	] {
+		assert_eq!(language.dotnet_culture(), culture);
+	}
+
+	for language in Language::all() {
+		assert_eq!(Language::from_dotnet_culture(&language.dotnet_culture()).unwrap(), language);
+	}
+}
+
+#[test]
+fn from_dotnet_culture_should_accept_legacy_names() {
+	for (culture, language) in [
+		("zh-CHS", Language::ZhHans),
+		("zh-CHT", Language::ZhHant),
+		("sr-SP-Latn", Language::SrLatnSp),
+		("sr-Latn-CS", Language::SrLatnSp),
+		("uz-UZ-Cyrl", Language::UzCyrlUz),
+		("div-MV", Language::Dv),
+		("es-ES_tradnl", Language::EsEs),
+		("de-DE_phoneb", Language::DeDe),
+	] {
+		assert_eq!(Language::from_dotnet_culture(culture).unwrap(), language);
+	}
+}
+
+#[test]
+fn from_dotnet_culture_should_reject_cultures_without_equivalent() {
+	for culture in ["", "es-419", "pt-AO", "xx-YY"] {
+		assert!(matches!(
+			Language::from_dotnet_culture(culture),
+			Err(Error::UnsupportedDotnetCulture(_))
+		));
+	}
+}
@@ -0,0 +1,71 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn gettext_plural_forms_should_agree_with_cldr() {
+	for language in Language::all() {
+		let header = language.gettext_plural_forms();
+		let forms = header.parse::<PluralForms>().unwrap();
+
+		assert!(forms.agrees_with(language), "{} disagrees with `{header}`.", language.tag());
+	}
+
+	assert_eq!(Language::En.gettext_plural_forms(), "nplurals=2; plural=(n==1 ? 0 : 1);");
+	assert_eq!(Language::Ja.gettext_plural_forms(), "nplurals=1; plural=0;");
+	assert!(Language::Ru.gettext_plural_forms().starts_with("nplurals=3;"));
+	assert!(Language::Pl.gettext_plural_forms().starts_with("nplurals=3;"));
+}
+
+#[test]
+fn plural_forms_should_evaluate_common_headers() {
+	let forms = PluralForms::try_from("Plural-Forms: nplurals=2; plural=(n != 1);").unwrap();
+
+	assert_eq!(forms.nplurals(), 2);
+	assert_eq!((forms.index(1).unwrap(), forms.index(0).unwrap()), (0, 1));
+	assert!(forms.agrees_with(Language::En));
+	assert!(!forms.agrees_with(Language::Fr));
+
+	let forms = PluralForms::try_from(
+		"nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);",
+	)
+	.unwrap();
+
+	assert_eq!([1, 2, 5, 11, 21, 22].map(|n| forms.index(n).unwrap()), [0, 1, 2, 2, 0, 1]);
+	// Russian `other` only covers decimals, so the classic three-form header agrees.
+	assert!(forms.agrees_with(Language::Ru));
+	assert!(forms.agrees_with(Language::Uk));
+	assert!(!forms.agrees_with(Language::Pl));
+}
+
+#[test]
+fn plural_forms_should_reject_invalid_headers() {
+	for invalid in [
+		"",
+		"nplurals=2;",
+		"plural=(n != 1);",
+		"nplurals=0; plural=0;",
+		"nplurals=2; plural=(n != 1;",
+		"nplurals=2; plural=n ? 1;",
+		"nplurals=2; plural=x;",
+		"nplurals=2; plural=0; plural=1;",
+	] {
+		assert!(PluralForms::try_from(invalid).is_err(), "{invalid}");
+	}
+
+	for nested in [
+		format!("nplurals=2; plural={}n{};", "(".repeat(100_000), ")".repeat(100_000)),
+		format!("nplurals=2; plural={}n;", "!".repeat(100_000)),
+		format!("nplurals=2; plural={}0;", "n+".repeat(100_000)),
+		format!("nplurals=2; plural={}0{};", "n ? 1 : ".repeat(100_000), ""),
+	] {
+		assert!(matches!(
+			PluralForms::try_from(nested.as_str()),
+			Err(Error::InvalidPluralForms(_))
+		));
+	}
+
+	let forms = PluralForms::try_from("nplurals=2; plural=n / (n - 1) ? 0 : 3;").unwrap();
+
+	assert!(forms.index(1).is_err());
+	assert!(forms.index(0).is_err());
+}
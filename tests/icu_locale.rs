@@ -35,3 +35,10 @@ fn unsupported_locale_should_fail() {
 
 	assert!(Language::try_from(locale).is_err());
 }
+
+#[test]
+fn unsupported_locale_should_have_a_closest_language() {
+	let locale = Locale::from_str("es-419-u-nu-latn").unwrap();
+
+	assert_eq!(Language::closest(&locale.to_string()).unwrap().language(), Language::Es);
+}
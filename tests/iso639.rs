@@ -0,0 +1,51 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn iso639_codes_should_follow_the_base_language() {
+	for (language, codes) in [
+		(Language::DeAt, (Some("de"), Some("deu"), Some("ger"), "deu")),
+		(Language::ZhHant, (Some("zh"), Some("zho"), Some("chi"), "zho")),
+		(Language::SrLatnSp, (Some("sr"), Some("srp"), Some("srp"), "srp")),
+		(Language::Fil, (None, Some("fil"), Some("fil"), "fil")),
+		(Language::Tl, (Some("tl"), Some("tgl"), Some("tgl"), "tgl")),
+		(Language::Prs, (None, None, None, "prs")),
+	] {
+		assert_eq!(
+			(language.iso639_1(), language.iso639_2t(), language.iso639_2b(), language.iso639_3()),
+			codes,
+			"{}",
+			language.tag()
+		);
+	}
+
+	for language in Language::all() {
+		let base = language.tag().split('-').next().unwrap();
+
+		assert!(language.iso639_1().is_none_or(|code| code == base), "{base}");
+		assert!([language.iso639_1(), Some(language.iso639_3())].contains(&Some(base)), "{base}");
+	}
+}
+
+#[test]
+fn from_iso639_should_resolve_the_base_language() {
+	assert_eq!(Language::from_iso639_1("DE").unwrap(), Language::De);
+	assert_eq!(Language::from_iso639_2t("deu").unwrap(), Language::De);
+	assert_eq!(Language::from_iso639_2b("ger").unwrap(), Language::De);
+	assert_eq!(Language::from_iso639_3("deu").unwrap(), Language::De);
+	assert_eq!(Language::from_iso639_1("zh").unwrap(), Language::ZhHans);
+	assert_eq!(Language::from_iso639_2b("chi").unwrap(), Language::ZhHans);
+	assert_eq!(Language::from_iso639_3("tgl").unwrap(), Language::Tl);
+	assert_eq!(Language::from_iso639_3("prs").unwrap(), Language::Prs);
+
+	for language in Language::all() {
+		let base = Language::from_iso639_3(language.iso639_3()).unwrap();
+
+		assert_eq!(base.iso639_3(), language.iso639_3());
+	}
+
+	assert!(Language::from_iso639_2t("ger").is_err());
+	assert!(
+		matches!(Language::from_iso639_1("xx"), Err(Error::UnsupportedIsoCode(code)) if code == "xx")
+	);
+}
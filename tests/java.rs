@@ -0,0 +1,45 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn java_locale_should_roundtrip() {
+	for (language, locale) in [
+		(Language::En, "en"),
+		(Language::ZhTw, "zh_TW"),
+		(Language::ZhHans, "zh__#Hans"),
+		(Language::SrLatnSp, "sr_RS_#Latn"),
+		(Language::En029, "en_029"),
+	] {
+		assert_eq!(language.java_locale(), locale);
+	}
+
+	for language in Language::all() {
+		assert_eq!(Language::from_java_locale(&language.java_locale()).unwrap(), language);
+	}
+}
+
+#[test]
+fn from_java_locale_should_accept_legacy_codes_and_ignore_extensions() {
+	for (locale, language) in [
+		("iw", Language::He),
+		("in", Language::Id),
+		("sr__#Latn", Language::SrLatnSp),
+		("ja_JP_JP_#u-ca-japanese", Language::Ja),
+		("th_TH_TH_#u-nu-thai", Language::Th),
+		("sr_RS_#Latn_u-nu-latn", Language::SrLatnSp),
+		("sr__#Latn_x-lvariant-foo", Language::SrLatnSp),
+		("zh_HK_#Hant", Language::ZhHk),
+	] {
+		assert_eq!(Language::from_java_locale(locale).unwrap(), language);
+	}
+}
+
+#[test]
+fn from_java_locale_should_reject_locales_without_equivalent() {
+	for locale in ["", "_US", "es_419", "pt_AO", "xx_YY", "en-US"] {
+		assert!(
+			matches!(Language::from_java_locale(locale), Err(Error::UnsupportedJavaLocale(_))),
+			"{locale}"
+		);
+	}
+}
@@ -44,3 +44,28 @@ fn names_should_not_be_empty() {
 		assert!(!language.local_name().is_empty(), "Autonym is missing.");
 	}
 }
+
+#[test]
+fn parse_lenient_should_normalize_case_and_separators() {
+	for (input, expected) in [
+		("en_us", Language::EnUs),
+		("EN-us", Language::EnUs),
+		("zh-hant", Language::ZhHant),
+		("ZH_hans", Language::ZhHans),
+		("sr_latn_sp", Language::SrLatnSp),
+		(" en-029 ", Language::En029),
+		("fil", Language::Fil),
+	] {
+		assert_eq!(Language::parse_lenient(input).unwrap(), expected, "{input}");
+		assert_eq!(input.parse::<Language>().unwrap(), expected, "{input}");
+	}
+
+	for invalid in ["", "en-", "en__us", "en-u$", "xx-YY"] {
+		assert!(
+			matches!(Language::parse_lenient(invalid), Err(Error::UnsupportedLanguageTag(tag)) if tag == invalid)
+		);
+	}
+
+	// The strict path keeps requiring the canonical spelling.
+	assert!(Language::try_from("en_us").is_err());
+}
@@ -0,0 +1,47 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn lcid_should_roundtrip() {
+	for (language, lcid) in [
+		(Language::EnUs, 0x0409),
+		(Language::En, 0x0009),
+		(Language::En029, 0x2409),
+		(Language::SrLatnSp, 0x081A),
+		(Language::SrCyrlSp, 0x0C1A),
+		(Language::MnMongCn, 0x0850),
+		(Language::ZhHant, 0x7C04),
+		(Language::ZhTw, 0x0404),
+		(Language::Nb, 0x7C14),
+	] {
+		assert_eq!(language.lcid(), Some(lcid), "{}", language.tag());
+	}
+
+	for language in Language::all() {
+		if let Some(lcid) = language.lcid() {
+			assert_eq!(Language::from_lcid(lcid).unwrap(), language, "{lcid:#06X}");
+		}
+	}
+
+	assert_eq!(Language::Ak.lcid(), None);
+}
+
+#[test]
+fn from_lcid_should_resolve_locales_missing_from_the_dataset() {
+	for (lcid, expected) in [
+		(0x0436, Language::Af),
+		(0x241A, Language::SrLatnSp),
+		(0x0C3B, Language::Se),
+		(0x7804, Language::ZhHans),
+		// German phonebook sort.
+		(0x0001_0407, Language::DeDe),
+	] {
+		assert_eq!(Language::from_lcid(lcid).unwrap(), expected, "{lcid:#06X}");
+	}
+
+	for invalid in [0x0000, 0x1000, 0x0430_0000, 0xFFFF_0409, 0x0010_0409] {
+		assert!(
+			matches!(Language::from_lcid(invalid), Err(Error::UnsupportedLcid(lcid)) if lcid == invalid)
+		);
+	}
+}
@@ -0,0 +1,113 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn closest_should_walk_up_to_a_supported_language() {
+	use MatchStep::*;
+
+	for (input, expected, steps) in [
+		("de-AT", Language::DeAt, vec![]),
+		("de-AT-1996", Language::DeAt, vec![Stripped]),
+		("en-US-u-ca-gregory", Language::EnUs, vec![Stripped]),
+		("en_us-x-private", Language::EnUs, vec![Stripped]),
+		("es-419", Language::Es, vec![Fallback]),
+		("pt-AO", Language::Pt, vec![Fallback]),
+		("zh-Hant-TW", Language::ZhTw, vec![LikelySubtags]),
+		("zh-Hant-SG", Language::ZhHant, vec![Fallback]),
+		("zh-Hans-SG", Language::ZhSg, vec![LikelySubtags]),
+		("sr-Latn-ME", Language::SrLatnSp, vec![Fallback]),
+		("mn-CN", Language::MnMongCn, vec![LikelySubtags]),
+		("und-TW", Language::ZhTw, vec![LikelySubtags]),
+	] {
+		let closest = Language::closest(input).unwrap();
+
+		assert_eq!((closest.language(), closest.steps()), (expected, steps.as_slice()), "{input}");
+	}
+
+	let closest = Language::closest("iw-IL").unwrap();
+
+	assert_eq!(closest.language(), Language::He);
+	assert!(
+		matches!(closest.steps(), [MatchStep::Alias(alias), MatchStep::LikelySubtags] if alias.deprecated() == "iw")
+	);
+
+	let closest = Language::closest("sr-Cyrl-RS").unwrap();
+
+	assert_eq!(closest.language(), Language::SrCyrlSp);
+	assert!(
+		matches!(closest.steps(), [MatchStep::Alias(alias)] if (alias.deprecated(), alias.preferred()) == ("SP", "RS"))
+	);
+	assert!(Language::closest("en").unwrap().is_exact());
+}
+
+#[test]
+fn closest_should_reject_unmatched_tags() {
+	for invalid in ["", "en-", "x-private", "i-klingon", "xx-YY", "ar-Latn"] {
+		assert!(
+			matches!(Language::closest(invalid), Err(Error::UnsupportedLanguageTag(tag)) if tag == invalid),
+			"{invalid}"
+		);
+	}
+}
+
+#[test]
+fn closest_should_prefer_regionless_variants_for_base_tags() {
+	assert_eq!(Language::closest("zh").unwrap().language(), Language::ZhHans);
+	assert_eq!(Language::closest("zh-Hant").unwrap().language(), Language::ZhHant);
+	assert_eq!(Language::closest("zh-CN").unwrap().language(), Language::ZhCn);
+}
+
+#[test]
+fn lookup_should_truncate_ranges_in_priority_order() {
+	let available = [Language::ZhHant, Language::De, Language::DeCh, Language::En, Language::FrCa];
+
+	for (ranges, expected) in [
+		(&["zh-Hant-CN-x-private1-private2"][..], Some(Language::ZhHant)),
+		(&["fr-FR", "de-AT", "en"], Some(Language::De)),
+		(&["DE-ch-1996"], Some(Language::DeCh)),
+		(&["de-*-CH"], Some(Language::DeCh)),
+		(&["*", "en-US"], Some(Language::En)),
+		(&["ja", "fr"], Some(Language::Af)),
+		(&["*"], Some(Language::Af)),
+		(&[], Some(Language::Af)),
+	] {
+		assert_eq!(lookup(ranges.iter().copied(), &available, Some(Language::Af)), expected);
+	}
+
+	assert_eq!(lookup(["ja"], &Language::all(), None), Some(Language::Ja));
+	assert_eq!(lookup(["ja-JP"], &available, None), None);
+}
+
+#[test]
+fn basic_filter_should_match_prefixes_in_priority_order() {
+	let available = [Language::De, Language::DeAt, Language::DeCh, Language::En, Language::EnUs];
+
+	assert_eq!(
+		basic_filter(["en", "de-ch"], &available),
+		[Language::En, Language::EnUs, Language::DeCh]
+	);
+	assert_eq!(basic_filter(["de-C"], &available), []);
+	assert_eq!(
+		basic_filter(["en-US", "*"], &available),
+		[Language::EnUs, Language::De, Language::DeAt, Language::DeCh, Language::En]
+	);
+	assert_eq!(basic_filter(["zh-Hant"], &Language::all()), [Language::ZhHant]);
+}
+
+#[test]
+fn extended_filter_should_match_wildcards_and_skip_subtags() {
+	let all = Language::all();
+
+	assert_eq!(extended_filter(["de-*-CH"], &all), [Language::DeCh]);
+	assert_eq!(extended_filter(["sr-*-SP"], &all), [Language::SrCyrlSp, Language::SrLatnSp]);
+	assert_eq!(extended_filter(["uz-UZ"], &all), [Language::UzCyrlUz, Language::UzLatnUz]);
+	assert_eq!(
+		extended_filter(["*-Latn"], &all).len(),
+		all.iter().filter(|l| l.tag().contains("-Latn")).count()
+	);
+	assert_eq!(
+		extended_filter(["zh-Hant", "zh"], &[Language::ZhTw, Language::ZhHant]),
+		[Language::ZhHant, Language::ZhTw]
+	);
+	assert_eq!(extended_filter(["de-DE"], &all), [Language::DeDe]);
+}
@@ -0,0 +1,127 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn plural_should_select_cldr_categories() {
+	let pattern = "{count, plural, =0 {No files} one {# file} other {# files}}";
+	let message = MessageFormat::new(Language::En, pattern).unwrap();
+	let format = |count: MessageArgument| message.format([("count", count)]).unwrap();
+
+	assert_eq!(format(0.into()), "No files");
+	assert_eq!(format(1.into()), "1 file");
+	assert_eq!(format(2.into()), "2 files");
+	assert_eq!(format(MessageArgument::Decimal("1.0")), "1.0 files");
+
+	let pattern = "{n, plural, one {# день} few {# дня} many {# дней} other {# дня}}";
+	let message = MessageFormat::new(Language::Ru, pattern).unwrap();
+	let format = |n: u32| message.format([("n", n.into())]).unwrap();
+
+	assert_eq!([1, 3, 5, 21].map(format), ["1 день", "3 дня", "5 дней", "21 день"]);
+}
+
+#[test]
+fn plural_should_apply_offset_after_exact_matches() {
+	let pattern = "{guests, plural, offset:1 =0 {Nobody} =1 {{host}} one {{host} and # other} other {{host} and # others}}";
+	let message = MessageFormat::new(Language::En, pattern).unwrap();
+	let format =
+		|guests: u8| message.format([("guests", guests.into()), ("host", "Ada".into())]).unwrap();
+
+	assert_eq!([0, 1, 2, 3].map(format), ["Nobody", "Ada", "Ada and 1 other", "Ada and 2 others"]);
+
+	let pattern = "{place, selectordinal, offset:1 =1 {winner} one {#st runner-up} two {#nd runner-up} few {#rd runner-up} other {#th runner-up}}";
+	let message = MessageFormat::new(Language::En, pattern).unwrap();
+	let format = |place: u8| message.format([("place", place.into())]).unwrap();
+
+	assert_eq!([1, 2, 3].map(format), ["winner", "1st runner-up", "2nd runner-up"]);
+}
+
+#[test]
+fn exact_matches_should_compare_numerically() {
+	let message =
+		MessageFormat::new(Language::En, "{n, plural, =1.0 {exact} =2 {two} other {#}}").unwrap();
+	let format = |n: MessageArgument| message.format([("n", n)]).unwrap();
+
+	assert_eq!(format(1.into()), "exact");
+	assert_eq!(format(MessageArgument::Decimal("1.0")), "exact");
+	assert_eq!(format(MessageArgument::Decimal("2.00")), "two");
+	assert_eq!(format(3.into()), "3");
+}
+
+#[test]
+fn selectordinal_and_select_should_nest() {
+	let pattern = "{gender, select, female {She} other {They}} finished {place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}.";
+	let message = MessageFormat::new(Language::En, pattern).unwrap();
+	let format = |gender: &'static str, place: u8| {
+		message.format([("gender", gender.into()), ("place", place.into())]).unwrap()
+	};
+
+	assert_eq!(format("female", 1), "She finished 1st.");
+	assert_eq!(format("male", 22), "They finished 22nd.");
+	assert_eq!(format("other", 13), "They finished 13th.");
+
+	let pattern = "{n, plural, one {{gender, select, female {her # item} other {their # item}}} other {# items}}";
+	let message = MessageFormat::new(Language::En, pattern).unwrap();
+
+	assert_eq!(
+		message.format([("n", 1.into()), ("gender", "female".into())]).unwrap(),
+		"her 1 item"
+	);
+}
+
+#[test]
+fn apostrophes_should_quote_syntax() {
+	let message =
+		MessageFormat::new(Language::En, "It''s '{literal}' and '#' {n, plural, other {'#' is #}}")
+			.unwrap();
+
+	assert_eq!(message.format([("n", 5.into())]).unwrap(), "It's {literal} and '#' # is 5");
+}
+
+#[test]
+fn invalid_messages_should_fail() {
+	for invalid in [
+		"{",
+		"}",
+		"{n, plural, one {x}}",
+		"{n, plural, some {x} other {y}}",
+		"{n, select, a {x}}",
+		"{n, date}",
+		"{n, number, integer}",
+		"{n, selectordinal, one {x} offset:1 other {y}}",
+		"{n, plural, other {x}",
+	] {
+		assert!(MessageFormat::new(Language::En, invalid).is_err(), "{invalid}");
+	}
+
+	let message = MessageFormat::new(Language::En, "{n, plural, other {#}} {who}").unwrap();
+
+	assert!(matches!(message.format([("n", 1.into())]), Err(Error::MissingMessageArgument(_))));
+	assert!(matches!(
+		message.format([("n", "one".into()), ("who", "me".into())]),
+		Err(Error::InvalidMessageArgument(_))
+	));
+}
+
+#[test]
+fn hostile_messages_and_arguments_should_fail() {
+	let nested = format!("{}x{}", "{n, select, other {".repeat(100_000), "}}".repeat(100_000));
+
+	assert!(matches!(
+		MessageFormat::new(Language::En, &nested),
+		Err(Error::InvalidMessageFormat(_))
+	));
+
+	let shallow = format!("{}x{}", "{n, select, other {".repeat(64), "}}".repeat(64));
+
+	assert!(MessageFormat::new(Language::En, &shallow).is_ok());
+
+	let message = MessageFormat::new(Language::En, "{n, plural, offset:1 other {#}}").unwrap();
+
+	for argument in [
+		MessageArgument::Integer(i128::MIN),
+		MessageArgument::Decimal("1c2000000000"),
+		MessageArgument::Decimal("1c18446744073709551615"),
+	] {
+		assert!(matches!(message.format([("n", argument)]), Err(Error::InvalidMessageArgument(_))));
+	}
+}
@@ -0,0 +1,55 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn from_path_should_find_language_in_file_names_and_directories() {
+	for (path, language, stem) in [
+		("messages.fr_CA.json", Language::FrCa, "messages"),
+		("strings/pt-BR/app.ftl", Language::PtBr, "app"),
+		("movie.en.forced.srt", Language::En, "movie.forced"),
+		("README.zh-Hans.md", Language::ZhHans, "README"),
+		("locales/de.json", Language::De, ""),
+		("pt-BR/messages.fr.json", Language::Fr, "messages"),
+		("subs/movie.pt-BR.SDH.cc.srt", Language::PtBr, "movie.SDH.cc"),
+		("it/report.is.final.txt", Language::It, "report.is.final"),
+	] {
+		let found = Language::from_path(path).unwrap();
+
+		assert_eq!((found.language(), found.stem()), (language, stem));
+	}
+}
+
+#[test]
+fn from_path_should_skip_extensions_and_non_canonical_segments() {
+	for path in [
+		"notes.as",
+		"strings/pt-br/app.ftl",
+		"EN.txt",
+		"",
+		"/",
+		"report.is.final.txt",
+		"notes.it.draft.md",
+		"be.my.guest.txt",
+		"movie.en.directors-cut.srt",
+	] {
+		assert!(matches!(Language::from_path(path), Err(Error::NoLanguageInPath(_))));
+	}
+}
+
+#[test]
+fn from_path_with_should_use_configured_separators() {
+	let options = PathOptions::default().name_separators(&['.', '_']).subtag_separators(&['-']);
+	let found = Language::from_path_with("messages_pt-BR.properties", &options).unwrap();
+
+	assert_eq!((found.language(), found.stem()), (Language::PtBr, "messages"));
+	assert!(Language::from_path_with("strings/fr_CA/app.ftl", &options).is_err());
+}
+
+#[test]
+fn from_path_with_should_skip_configured_segments() {
+	let options = PathOptions::default().ignored_segments(&["min"]);
+	let found = Language::from_path_with("app.de.min.js", &options).unwrap();
+
+	assert_eq!((found.language(), found.stem()), (Language::De, "app.min"));
+	assert!(Language::from_path_with("movie.en.forced.srt", &options).is_err());
+}
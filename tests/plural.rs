@@ -0,0 +1,311 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn plural_categories_should_end_with_other() {
+	for language in Language::all() {
+		let categories = language.plural_categories();
+
+		assert_eq!(categories.last(), Some(&PluralCategory::Other), "{}", language.tag());
+		assert!(categories.is_sorted(), "Categories out of CLDR order for {}.", language.tag());
+	}
+}
+
+#[test]
+fn plural_categories_should_follow_cldr() {
+	use PluralCategory::*;
+
+	assert_eq!(Language::En.plural_categories(), [One, Other]);
+	assert_eq!(Language::Ja.plural_categories(), [Other]);
+	assert_eq!(Language::Ru.plural_categories(), [One, Few, Many, Other]);
+	assert_eq!(Language::Ar.plural_categories(), [Zero, One, Two, Few, Many, Other]);
+	assert_eq!(Language::PtPt.plural_categories(), [One, Many, Other]);
+}
+
+#[test]
+fn plural_category_keywords_should_roundtrip() {
+	for category in PluralCategory::all() {
+		assert_eq!(PluralCategory::try_from(category.keyword()).unwrap(), category);
+	}
+
+	assert!(PluralCategory::try_from("several").is_err());
+}
+
+#[test]
+fn plural_category_should_follow_cldr() {
+	use PluralCategory::*;
+
+	assert_eq!(Language::En.plural_category(1), One);
+	assert_eq!(Language::En.plural_category(0), Other);
+	assert_eq!(Language::Ja.plural_category(1), Other);
+	assert_eq!(Language::Ru.plural_category(21), One);
+	assert_eq!(Language::Ru.plural_category(22), Few);
+	assert_eq!(Language::Ru.plural_category(11), Many);
+	assert_eq!(Language::Ar.plural_category(0), Zero);
+	assert_eq!(Language::Ar.plural_category(3), Few);
+	assert_eq!(Language::Ar.plural_category(111), Many);
+	assert_eq!(Language::Ar.plural_category(100), Other);
+	assert_eq!(Language::Fr.plural_category(0), One);
+	assert_eq!(Language::Fr.plural_category(1_000_000), Many);
+}
+
+#[test]
+fn plural_category_should_be_declared() {
+	for language in Language::all() {
+		for n in (0..=1_000).chain([1_000_000, 2_000_000]) {
+			let category = language.plural_category(n);
+
+			assert!(
+				language.plural_categories().contains(&category),
+				"{} selects undeclared `{category}` for {n}.",
+				language.tag()
+			);
+		}
+	}
+}
+
+#[test]
+fn plural_operands_should_parse() {
+	let operands = PluralOperands::try_from("-1.50").unwrap();
+
+	assert_eq!((operands.n(), operands.i(), operands.v(), operands.w()), (1.5, 1, 2, 1));
+	assert_eq!((operands.f(), operands.t(), operands.c()), (50, 5, 0));
+
+	let operands = PluralOperands::try_from("1.2c3").unwrap();
+
+	assert_eq!((operands.i(), operands.v(), operands.c()), (1_200, 0, 3));
+	assert_eq!(PluralOperands::from_f64(1.5, 2).unwrap(), "1.50".parse().unwrap());
+	assert_eq!(PluralOperands::from(-3_i32), "3".parse().unwrap());
+
+	for invalid in ["", "1.", ".5", "1.5.0", "abc", "1c"] {
+		assert!(invalid.parse::<PluralOperands>().is_err(), "{invalid} should be rejected.");
+	}
+}
+
+#[test]
+fn plural_operands_should_reject_oversized_exponents() {
+	assert_eq!(PluralOperands::try_from("1c19").unwrap().i(), 10_000_000_000_000_000_000);
+
+	for invalid in ["1c20", "1c2000000000", "1c18446744073709551615", "1e99999999999999999999"] {
+		assert!(
+			matches!(PluralOperands::try_from(invalid), Err(Error::InvalidPluralOperands(_))),
+			"{invalid} should be rejected."
+		);
+	}
+}
+
+#[test]
+fn plural_category_for_should_use_decimal_operands() {
+	use PluralCategory::*;
+
+	let category =
+		|language: Language, number: &str| language.plural_category_for(&number.parse().unwrap());
+
+	assert_eq!(category(Language::En, "1"), One);
+	assert_eq!(category(Language::En, "1.0"), Other);
+	assert_eq!(category(Language::Fr, "1.5"), One);
+	assert_eq!(category(Language::Fr, "1c6"), Many);
+	assert_eq!(category(Language::Ru, "1.5"), Other);
+	assert_eq!(category(Language::Lv, "0.1"), One);
+	assert_eq!(category(Language::Be, "21.0"), One);
+	assert_eq!(category(Language::Be, "21.5"), Other);
+}
+
+#[test]
+fn ordinal_category_should_follow_cldr() {
+	use PluralCategory::*;
+
+	let en = [(1, One), (2, Two), (3, Few), (4, Other), (11, Other), (12, Other), (13, Other)];
+
+	for (n, category) in en.into_iter().chain([(21, One), (22, Two), (23, Few), (111, Other)]) {
+		assert_eq!(Language::En.ordinal_category(n), category, "en {n}");
+	}
+
+	assert_eq!(Language::En.ordinal_categories(), [One, Two, Few, Other]);
+	assert_eq!(Language::Ja.ordinal_categories(), [Other]);
+	assert_eq!(Language::Fr.ordinal_category(1), One);
+	assert_eq!(Language::Fr.ordinal_category(2), Other);
+	assert_eq!(Language::It.ordinal_category(11), Many);
+	assert_eq!(Language::Sv.ordinal_category(22), One);
+}
+
+#[test]
+fn ordinal_category_should_be_declared() {
+	for language in Language::all() {
+		let categories = language.ordinal_categories();
+
+		assert_eq!(categories.last(), Some(&PluralCategory::Other), "{}", language.tag());
+
+		for n in (0..=1_000).chain([1_000_000, 2_000_000]) {
+			let category = language.ordinal_category(n);
+
+			assert!(
+				categories.contains(&category),
+				"{} selects undeclared ordinal `{category}` for {n}.",
+				language.tag()
+			);
+		}
+	}
+}
+
+#[test]
+fn plural_rule_should_parse_and_evaluate() {
+	let rule = PluralRule::try_from(
+		"n % 10 = 1 and n % 100 != 11,71,91 @integer 1, 21, 31, 101, … @decimal 1.0, 21.0~21.2",
+	)
+	.unwrap();
+	let relation = &rule.condition()[0][1];
+
+	assert_eq!(relation.operand(), PluralOperand::N);
+	assert_eq!(relation.modulus(), Some(100));
+	assert!(relation.negated());
+	assert_eq!(relation.ranges(), [(11, 11), (71, 71), (91, 91)]);
+	assert!(rule.integer_samples().unwrap().is_unbounded());
+	assert_eq!(rule.decimal_samples().unwrap().values(), ["1.0", "21.0", "21.1", "21.2"]);
+
+	for (number, expected) in
+		[("1", true), ("21", true), ("11", false), ("1.0", true), ("1.5", false)]
+	{
+		assert_eq!(rule.matches(&number.parse().unwrap()), expected, "{number}");
+	}
+
+	assert!(PluralRule::try_from("").unwrap().matches(&PluralOperands::from(7_u8)));
+	assert!(PluralRule::try_from("i=0..2,5 and v=0").unwrap().matches(&PluralOperands::from(5_u8)));
+
+	for invalid in
+		["x = 1", "n = ", "n % 0 = 1", "n = 3..1", "n == 1", "n = 1 @decimal 1.0 @integer 1"]
+	{
+		assert!(PluralRule::try_from(invalid).is_err(), "{invalid}");
+	}
+}
+
+#[test]
+fn plural_rules_should_select_and_validate_samples() {
+	use PluralCategory::*;
+
+	let rules = PluralRules::parse([
+		("one", "i = 1 and v = 0 @integer 1"),
+		("other", " @integer 0, 2~16, 100, … @decimal 0.0~1.5"),
+	])
+	.unwrap();
+
+	assert_eq!(rules.categories().collect::<Vec<_>>(), [One, Other]);
+	assert_eq!(rules.select(&PluralOperands::from(1_u8)), One);
+	assert_eq!(rules.select(&"1.0".parse().unwrap()), Other);
+	assert!(rules.validate().is_ok());
+
+	let rules = PluralRules::parse([("one", "n = 1 @integer 1, 2"), ("other", "")]).unwrap();
+
+	assert!(matches!(
+		rules.validate(),
+		Err(Error::PluralSampleMismatch { expected: One, actual: Other, .. })
+	));
+	assert!(PluralRules::parse([("one", "n = 1")]).is_err());
+	assert!(PluralRules::parse([("other", ""), ("other", "")]).is_err());
+	assert!(PluralRules::parse([("several", "n = 1"), ("other", "")]).is_err());
+
+	for oversized in ["@integer 0~100000000", "@integer 0~600, 1000~1600", "@decimal 0.000~1.000"] {
+		assert!(matches!(
+			PluralRules::parse([("other", oversized)]),
+			Err(Error::InvalidPluralRule(_))
+		));
+	}
+
+	assert!(PluralRules::parse([("other", "@integer 0~999")]).unwrap().validate().is_ok());
+}
+
+#[test]
+fn plural_range_category_should_follow_cldr() {
+	use PluralCategory::*;
+
+	let range = |language: Language, start: u64, end: u64| {
+		language
+			.plural_range_category(language.plural_category(start), language.plural_category(end))
+	};
+
+	assert_eq!(range(Language::Ru, 1, 2), Few);
+	assert_eq!(range(Language::Ru, 1, 5), Many);
+	assert_eq!(range(Language::Ru, 2, 21), One);
+	assert_eq!(range(Language::Pl, 2, 5), Many);
+	assert_eq!(range(Language::En, 1, 3), Other);
+	assert_eq!(range(Language::Ro, 2, 1), Few);
+	assert_eq!(range(Language::Sl, 2, 101), Few);
+	assert_eq!(range(Language::Mk, 1, 21), Other);
+	assert_eq!(range(Language::Ja, 1, 2), Other);
+}
+
+#[test]
+fn plural_range_category_should_be_declared() {
+	for language in Language::all() {
+		let categories = language.plural_categories();
+
+		for start in categories {
+			for end in categories {
+				let category = language.plural_range_category(*start, *end);
+
+				assert!(categories.contains(&category), "{} {start}–{end}", language.tag());
+			}
+		}
+	}
+}
+
+#[test]
+fn plural_samples_should_select_their_category() {
+	for language in Language::all() {
+		for category in PluralCategory::all() {
+			let Some(samples) = language.plural_samples(category) else {
+				assert!(!language.plural_categories().contains(&category), "{}", language.tag());
+
+				continue;
+			};
+
+			assert!(!samples.integers().is_empty() || !samples.decimals().is_empty());
+
+			for sample in samples.integers().iter().chain(samples.decimals()) {
+				let operands = sample.parse().unwrap();
+
+				assert_eq!(
+					language.plural_category_for(&operands),
+					category,
+					"{} {sample}",
+					language.tag()
+				);
+			}
+		}
+	}
+
+	let one = Language::En.plural_samples(PluralCategory::One).unwrap();
+
+	assert_eq!(one.integers(), ["1"]);
+	assert!(one.decimals().is_empty());
+	assert!(Language::En.plural_samples(PluralCategory::Few).is_none());
+	assert!(Language::Ru.plural_samples(PluralCategory::One).unwrap().integers().contains(&"21"));
+}
+
+#[test]
+fn plural_compatibility_should_report_missing_and_extra_categories() {
+	use PluralCategory::*;
+
+	assert!(Language::PtBr.plural_compatibility(Language::Pt).is_same());
+	assert!(Language::SrLatnSp.plural_compatibility(Language::Sr).is_compatible());
+
+	let compatibility = Language::Ru.plural_compatibility(Language::En);
+
+	assert!(!compatibility.is_compatible());
+	assert_eq!(compatibility.missing_cardinal(), [Few, Many]);
+	assert!(compatibility.missing_ordinal().is_empty());
+
+	let compatibility = Language::En.plural_compatibility(Language::De);
+
+	assert!(compatibility.missing_cardinal().is_empty());
+	assert_eq!(compatibility.missing_ordinal(), [One, Two, Few]);
+	assert!(compatibility.extra_ordinal().is_empty());
+
+	// `ja` only needs `other`, which every language provides, but `ar` has more categories.
+	let compatibility = Language::Ja.plural_compatibility(Language::Ar);
+
+	assert!(compatibility.is_compatible());
+	assert!(!compatibility.is_same());
+	assert_eq!(compatibility.extra_cardinal(), [Zero, One, Two, Few, Many]);
+	assert!(compatibility.extra_ordinal().is_empty());
+}
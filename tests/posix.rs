@@ -0,0 +1,45 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn from_posix_should_map_locale_identifiers() {
+	for (locale, expected) in [
+		("pt_BR.UTF-8", Language::PtBr),
+		("en_US", Language::EnUs),
+		("de_AT.ISO-8859-1@euro", Language::DeAt),
+		("sr_RS@latin", Language::SrLatnSp),
+		("sr_RS@cyrillic", Language::SrCyrlSp),
+		("sr_RS", Language::Sr),
+		("uz_UZ.UTF-8@cyrillic", Language::UzCyrlUz),
+		("bs_BA@cyrillic", Language::BsCyrl),
+		("zh@hant", Language::ZhHant),
+		("ca_ES.UTF-8", Language::Ca),
+		("ja", Language::Ja),
+		("C", Language::En),
+		("POSIX", Language::En),
+		("C.UTF-8", Language::En),
+	] {
+		assert_eq!(Language::from_posix(locale).unwrap(), expected, "{locale}");
+	}
+
+	for invalid in ["", "english", "xx_YY", "en_USA", "zh_TW@cyrillic", "e1_US"] {
+		assert!(Language::from_posix(invalid).is_err(), "{invalid}");
+	}
+}
+
+#[test]
+fn to_posix_should_roundtrip() {
+	assert_eq!(Language::PtBr.to_posix(), "pt_BR");
+	assert_eq!(Language::SrLatnSp.to_posix(), "sr_RS@latin");
+	assert_eq!(Language::UzCyrlUz.to_posix(), "uz_UZ@cyrillic");
+	assert_eq!(Language::ZhHant.to_posix(), "zh@hant");
+	assert_eq!(Language::ZhHans.to_posix(), "zh@hans");
+	assert_eq!(Language::ZhTw.to_posix(), "zh_TW");
+	assert_eq!(Language::MnMongCn.to_posix(), "mn_CN@mongolian");
+
+	for language in Language::all() {
+		let posix = language.to_posix();
+
+		assert_eq!(Language::from_posix(&posix).unwrap(), language, "{posix}");
+	}
+}
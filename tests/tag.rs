@@ -0,0 +1,139 @@
+// self
+use language::prelude::*;
+
+#[test]
+fn language_tag_should_parse_every_subtag_kind() {
+	let tag =
+		LanguageTag::try_from("ZH-yue-hant-hk-1996-fonipa-U-ca-chinese-a-bbb-X-private-1").unwrap();
+
+	assert_eq!(tag.language(), Some("zh"));
+	assert_eq!(tag.extlangs(), ["yue"]);
+	assert_eq!(tag.script(), Some("Hant"));
+	assert_eq!(tag.region(), Some("HK"));
+	assert_eq!(tag.variants(), ["1996", "fonipa"]);
+	assert_eq!(tag.extensions().len(), 2);
+	assert_eq!(tag.extensions()[0].singleton(), 'u');
+	assert_eq!(tag.extensions()[0].subtags(), ["ca", "chinese"]);
+	assert_eq!(tag.private_use(), ["private", "1"]);
+	assert_eq!(tag.to_string(), "zh-yue-Hant-HK-1996-fonipa-u-ca-chinese-a-bbb-x-private-1");
+
+	let tag = LanguageTag::try_from("es-419").unwrap();
+
+	assert_eq!((tag.language(), tag.region()), (Some("es"), Some("419")));
+
+	let tag = LanguageTag::try_from("x-whatever").unwrap();
+
+	assert!(tag.is_private_use());
+	assert_eq!(tag.language(), None);
+
+	let tag = LanguageTag::try_from("EN-gb-OED").unwrap();
+
+	assert!(tag.is_grandfathered());
+	assert_eq!(tag.to_string(), "en-GB-oed");
+	assert!(LanguageTag::try_from("zh-min-nan").unwrap().is_grandfathered());
+}
+
+#[test]
+fn language_tag_should_reject_malformed_tags() {
+	for invalid in [
+		"",
+		"e",
+		"en-",
+		"-en",
+		"en--US",
+		"toolonglanguage",
+		"en-a",
+		"en-a-x-1",
+		"en-x",
+		"en-US-x-toolongsubtag",
+		"1en",
+		"en-US-abc",
+		"en-ü",
+	] {
+		assert!(
+			matches!(LanguageTag::try_from(invalid), Err(Error::InvalidLanguageTag(tag)) if tag == invalid),
+			"{invalid}"
+		);
+	}
+}
+
+#[test]
+fn language_tag_should_map_to_language() {
+	for language in Language::all() {
+		let tag = LanguageTag::from(language);
+
+		assert_eq!(tag.to_string(), language.tag());
+		assert_eq!(Language::try_from(&tag).unwrap(), language);
+	}
+
+	let tag = LanguageTag::try_from("sr-latn-sp").unwrap();
+
+	assert_eq!(Language::try_from(&tag).unwrap(), Language::SrLatnSp);
+	assert!(Language::try_from(&LanguageTag::try_from("en-US-u-ca-gregory").unwrap()).is_err());
+}
+
+#[test]
+fn maximize_should_add_likely_subtags() {
+	for (input, expected) in [
+		("zh-TW", "zh-Hant-TW"),
+		("zh-Hant", "zh-Hant-TW"),
+		("zh", "zh-Hans-CN"),
+		("sr", "sr-Cyrl-RS"),
+		("sr-Latn", "sr-Latn-RS"),
+		("sr-ME", "sr-Latn-ME"),
+		("mn-Mong", "mn-Mong-CN"),
+		("und-TW", "zh-Hant-TW"),
+		("und-Cyrl", "ru-Cyrl-RU"),
+		("und", "en-Latn-US"),
+		("iw", "he-Hebr-IL"),
+		("sh-YU", "sr-Latn-RS"),
+		("de-CH-1996-u-co-phonebk", "de-Latn-CH-1996-u-co-phonebk"),
+		("xx", "xx"),
+		("x-private", "x-private"),
+	] {
+		assert_eq!(
+			LanguageTag::try_from(input).unwrap().maximize().to_string(),
+			expected,
+			"{input}"
+		);
+	}
+
+	for language in Language::all() {
+		let max = language.maximize();
+
+		assert!(
+			max.language().is_some() && max.script().is_some() && max.region().is_some(),
+			"{max}"
+		);
+	}
+
+	assert_eq!(Language::SrCyrlSp.maximize().to_string(), "sr-Cyrl-RS");
+	assert_eq!(Language::ZhTw.maximize().to_string(), "zh-Hant-TW");
+}
+
+#[test]
+fn minimize_should_remove_likely_subtags() {
+	for (input, expected) in [
+		("az-Latn-AZ", "az"),
+		("zh-Hant-TW", "zh-TW"),
+		("zh-Hans-CN", "zh"),
+		("sr-Cyrl-RS", "sr"),
+		("sr-Latn-RS", "sr-Latn"),
+		("en-Latn-GB", "en-GB"),
+		("und-Hant", "zh-TW"),
+		("de-Latn-DE-u-co-phonebk", "de-u-co-phonebk"),
+	] {
+		assert_eq!(
+			LanguageTag::try_from(input).unwrap().minimize().to_string(),
+			expected,
+			"{input}"
+		);
+	}
+
+	for language in Language::all() {
+		assert_eq!(language.minimize().maximize(), language.maximize(), "{}", language.tag());
+	}
+
+	assert_eq!(Language::AzLatnAz.minimize().to_string(), "az");
+	assert_eq!(Language::ZhHant.minimize().to_string(), "zh-TW");
+}
@@ -35,6 +35,19 @@ fn tagalog_maps_to_tgl() {
 	assert_eq!(Lang::try_from(Language::Fil).unwrap(), Lang::Tgl);
 }
 
+#[test]
+fn iso639_codes_map_every_shared_language() {
+	for (language, lang) in [
+		(Language::Ak, Lang::Aka),
+		(Language::Eo, Lang::Epo),
+		(Language::Jv, Lang::Jav),
+		(Language::Sn, Lang::Sna),
+	] {
+		assert_eq!(Lang::try_from(language).unwrap(), lang);
+		assert_eq!(Language::try_from(lang).unwrap(), language);
+	}
+}
+
 #[test]
 fn whatlang_prefers_canonical_locale() {
 	assert_eq!(Language::try_from(Lang::Spa).unwrap(), Language::Es);